@@ -0,0 +1,82 @@
+//! Parse, part 1 and part 2 benchmarks for every day, run against the real `input.txt` with the
+//! parameters in `aoc.toml`.
+//!
+//! Only parts with a recorded answer in `answers.toml` are benchmarked, and a day with none, like
+//! one just created with `aoc new`, is skipped altogether. To measure a
+//! redesign, save a baseline before the change and compare against it afterwards:
+//!
+//! ```text
+//! cargo bench -p aoc-bench -- --save-baseline before
+//! cargo bench -p aoc-bench -- --baseline before
+//! ```
+//!
+//! A single day can be selected with a filter, e.g. `cargo bench -p aoc-bench -- day-12`.
+
+use aoc::answers::Answers;
+use aoc::days;
+use aoc_common::config;
+use aoc_common::config::Config;
+use aoc_common::input::read_string;
+use aoc_common::solution::{Part, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+
+// Seconds per iteration: sample as little as criterion allows.
+const SLOW_DAYS: &[u32] = &[12, 15];
+
+fn bench_day<S: Solution>(c: &mut Criterion, answers: &Answers, configs: &Config, day: u32) {
+    // A new day may not have its input yet.
+    if Part::ALL
+        .iter()
+        .all(|part| answers.get(day, *part).is_none())
+    {
+        return;
+    }
+    let path = days::find(day).unwrap().default_input();
+    let input = read_string(path).unwrap();
+    let parsed = S::parse(&input).unwrap();
+    let config = config::parse::<S::Config>(&configs.get(day)).unwrap();
+
+    let mut group = c.benchmark_group(format!("day-{:02}", day));
+    if SLOW_DAYS.contains(&day) {
+        group.sample_size(10).sampling_mode(SamplingMode::Flat);
+    }
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    if answers.get(day, Part::One).is_some() {
+        group.bench_function("part1", |b| {
+            b.iter(|| S::part1(black_box(&parsed), &config))
+        });
+    }
+    if answers.get(day, Part::Two).is_some() {
+        group.bench_function("part2", |b| {
+            b.iter(|| S::part2(black_box(&parsed), &config))
+        });
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    let answers = Answers::load(Answers::default_path()).unwrap();
+    let configs = Config::load(Config::default_path()).unwrap();
+
+    bench_day::<day_01::Day01>(c, &answers, &configs, 1);
+    bench_day::<day_02::Day02>(c, &answers, &configs, 2);
+    bench_day::<day_03::Day03>(c, &answers, &configs, 3);
+    bench_day::<day_04::Day04>(c, &answers, &configs, 4);
+    bench_day::<day_05::Day05>(c, &answers, &configs, 5);
+    bench_day::<day_06::Day06>(c, &answers, &configs, 6);
+    bench_day::<day_07::Day07>(c, &answers, &configs, 7);
+    bench_day::<day_08::Day08>(c, &answers, &configs, 8);
+    bench_day::<day_09::Day09>(c, &answers, &configs, 9);
+    bench_day::<day_10::Day10>(c, &answers, &configs, 10);
+    bench_day::<day_11::Day11>(c, &answers, &configs, 11);
+    bench_day::<day_12::Day12>(c, &answers, &configs, 12);
+    bench_day::<day_13::Day13>(c, &answers, &configs, 13);
+    bench_day::<day_14::Day14>(c, &answers, &configs, 14);
+    bench_day::<day_15::Day15>(c, &answers, &configs, 15);
+    bench_day::<day_17::Day17>(c, &answers, &configs, 17);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
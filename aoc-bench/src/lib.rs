@@ -0,0 +1 @@
+//! Criterion benchmarks for every day live in `benches/days.rs`.
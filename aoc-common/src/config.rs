@@ -0,0 +1,153 @@
+use crate::input::read_string;
+use anyhow::{anyhow, Context, Result};
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Puzzle parameters for every day, as written in `aoc.toml`. Days without a section use the
+/// defaults in their `Config`.
+#[derive(Debug, Default)]
+pub struct Config {
+    days: BTreeMap<u32, Table>,
+}
+
+impl Config {
+    /// `aoc.toml` at the root of the workspace, which the runner and the days' binaries share.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("aoc.toml")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Config> {
+        Self::parse(&read_string(path)?)
+    }
+
+    pub fn parse(s: &str) -> Result<Config> {
+        Ok(Config {
+            days: parse_days(s)?,
+        })
+    }
+
+    pub fn get(&self, day: u32) -> Table {
+        self.days.get(&day).cloned().unwrap_or_default()
+    }
+}
+
+/// Parses a TOML document with one section per day, like `[7]`.
+pub fn parse_days<T: DeserializeOwned>(s: &str) -> Result<BTreeMap<u32, T>> {
+    toml::from_str::<BTreeMap<String, T>>(s)?
+        .into_iter()
+        .map(|(day, value)| {
+            let day = day
+                .parse()
+                .with_context(|| format!("invalid day: '{}'", day))?;
+            Ok((day, value))
+        })
+        .collect()
+}
+
+/// Deserializes a day's parameters from its table, using the defaults for the missing ones. A day
+/// without parameters has `()` as its `Config`, which only an empty table gives.
+pub fn parse<C: Default + DeserializeOwned>(table: &Table) -> Result<C> {
+    if table.is_empty() {
+        return Ok(C::default());
+    }
+    Value::Table(table.clone())
+        .try_into()
+        .context("invalid configuration")
+}
+
+/// Parses a `key=value` override. The value is read as TOML, or as a plain string if it is
+/// not valid TOML.
+pub fn parse_override(s: &str) -> Result<(String, Value)> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid override: '{}', expected key=value", s))?;
+    let value = format!("value = {}", value)
+        .parse::<Table>()
+        .ok()
+        .and_then(|mut t| t.remove("value"))
+        .unwrap_or_else(|| Value::String(value.to_string()));
+    Ok((key.trim().to_string(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Params {
+        rounds: u32,
+        source: (u32, u32),
+    }
+
+    impl Default for Params {
+        fn default() -> Self {
+            Params {
+                rounds: 20,
+                source: (500, 0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_config() -> Result<()> {
+        let config = Config::parse(
+            r#"
+[14]
+source = [500, 0]
+
+[15]
+row = 2_000_000
+"#,
+        )?;
+
+        assert_eq!(config.get(14), "source = [500, 0]".parse::<Table>()?);
+        assert_eq!(config.get(15), "row = 2_000_000".parse::<Table>()?);
+        assert!(config.get(1).is_empty());
+        assert!(Config::parse("[day-1]\nrow = 1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let table = |s: &str| s.parse::<Table>().unwrap();
+        assert_eq!(parse::<Params>(&Table::new())?, Params::default());
+        assert_eq!(
+            parse::<Params>(&table("rounds = 3"))?,
+            Params {
+                rounds: 3,
+                source: (500, 0)
+            }
+        );
+        assert!(parse::<Params>(&table("round = 3")).is_err());
+        assert!(parse::<()>(&table("rounds = 3")).is_err());
+        parse::<()>(&Table::new())
+    }
+
+    #[test]
+    fn test_parse_override() -> Result<()> {
+        assert_eq!(
+            parse_override("row=10")?,
+            ("row".to_string(), Value::Integer(10))
+        );
+        assert_eq!(
+            parse_override("source=[1, 2]")?,
+            (
+                "source".to_string(),
+                Value::Array(vec![Value::Integer(1), Value::Integer(2)])
+            )
+        );
+        assert_eq!(
+            parse_override("name=foo")?,
+            ("name".to_string(), Value::String("foo".to_string()))
+        );
+        assert!(parse_override("row").is_err());
+        Ok(())
+    }
+}
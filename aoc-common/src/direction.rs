@@ -0,0 +1,136 @@
+use crate::point::Point2;
+
+/// One of the four directions along the axes. `Up` is towards negative `y`, as in a `Grid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the four directions along the axes or the four diagonals, clockwise from `N`, which is
+/// towards negative `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction4 {
+    /// Clockwise from `Up`.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn unit(self) -> Point2 {
+        match self {
+            Direction4::Up => Point2::new(0, -1),
+            Direction4::Right => Point2::new(1, 0),
+            Direction4::Down => Point2::new(0, 1),
+            Direction4::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction4 {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction4 {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction4 {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl Direction8 {
+    /// Clockwise from `N`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    pub fn unit(self) -> Point2 {
+        match self {
+            Direction8::N => Point2::new(0, -1),
+            Direction8::NE => Point2::new(1, -1),
+            Direction8::E => Point2::new(1, 0),
+            Direction8::SE => Point2::new(1, 1),
+            Direction8::S => Point2::new(0, 1),
+            Direction8::SW => Point2::new(-1, 1),
+            Direction8::W => Point2::new(-1, 0),
+            Direction8::NW => Point2::new(-1, -1),
+        }
+    }
+
+    /// Turns by 45 degrees clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(d: Direction4) -> Direction8 {
+        match d {
+            Direction4::Up => Direction8::N,
+            Direction4::Right => Direction8::E,
+            Direction4::Down => Direction8::S,
+            Direction4::Left => Direction8::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.opposite(), Direction4::Right);
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+        assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+        assert_eq!(Direction8::SE.opposite(), Direction8::NW);
+    }
+
+    #[test]
+    fn test_unit() {
+        for d in Direction4::ALL {
+            assert_eq!(d.unit(), Direction8::from(d).unit());
+            assert_eq!(d.unit() + d.opposite().unit(), Point2::ORIGIN);
+        }
+        for d in Direction8::ALL {
+            assert_eq!(d.turn_right().turn_right().unit(), {
+                let u = d.unit();
+                Point2::new(-u.y, u.x)
+            });
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use crate::direction::{Direction4, Direction8};
+use crate::parse::ParseError;
+use crate::point::Point2;
+use anyhow::{anyhow, bail, Result};
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// A cell position, as `(x, y)`: `x` is the column and `y` the row, both from the top left.
+pub type Pos = (usize, usize);
+
+/// A dense, rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let width = rows.first().map(Vec::len).unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, n) in rows.into_iter().zip(1..) {
+            if row.len() != width {
+                bail!("row {} has {} cells, expected {}", n, row.len(), width);
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a grid with one line per row, converting every character with `f`. Blank lines
+    /// after the last row are ignored.
+    pub fn parse<F: Fn(char) -> Result<T>>(s: &str, f: F) -> Result<Grid<T>> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        // Carriage returns ending the last line would not survive printing the grid.
+        let rows = s.trim_end_matches(['\n', '\r']);
+        for l in rows.lines().map(|l| l.trim_end_matches('\r')) {
+            for (i, c) in l.char_indices() {
+                cells.push(f(c).map_err(|e| ParseError::locate(e, s, &l[i..i + c.len_utf8()]))?);
+            }
+            let n = l.chars().count();
+            if *width.get_or_insert(n) != n {
+                let message = format!("row has {} cells, expected {}", n, width.unwrap());
+                return Err(ParseError::new(s, l, message).into());
+            }
+        }
+        let width = width.unwrap_or(0);
+        Ok(Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, p: Pos) -> Option<&T> {
+        self.contains(p)
+            .then(|| &self.cells[p.1 * self.width + p.0])
+    }
+
+    pub fn get_mut(&mut self, p: Pos) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.1 * self.width + p.0])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, row by row, matching `predicate`.
+    pub fn position<P: Fn(&T) -> bool>(&self, predicate: P) -> Option<Pos> {
+        self.iter().find(|(_, c)| predicate(c)).map(|(p, _)| p)
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is outside the {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The positions one step from `p` by each of `steps`, skipping those outside the grid.
+    fn around<I: IntoIterator<Item = Point2>>(
+        &self,
+        p: Pos,
+        steps: I,
+    ) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        steps
+            .into_iter()
+            .filter_map(move |d| step(p, d).filter(|&(x, y)| x < width && y < height))
+    }
+
+    /// The up to 4 positions sharing a side with `p`, in the order of `Direction4::ALL`.
+    pub fn neighbours4(&self, p: Pos) -> impl Iterator<Item = Pos> {
+        self.around(p, Direction4::ALL.map(Direction4::unit))
+    }
+
+    /// The up to 8 positions sharing a side or a corner with `p`, in the order of
+    /// `Direction8::ALL`.
+    pub fn neighbours8(&self, p: Pos) -> impl Iterator<Item = Pos> {
+        self.around(p, Direction8::ALL.map(Direction8::unit))
+    }
+
+    /// The cells from `p`, excluded, to the edge of the grid, stepping by `d`, which must not be
+    /// zero.
+    pub fn ray(&self, p: Pos, d: Point2) -> impl Iterator<Item = (Pos, &T)> {
+        assert!(
+            d != Point2::ORIGIN,
+            "a ray from {:?} would never reach the edge",
+            p
+        );
+        std::iter::successors(step(p, d), move |p| step(*p, d))
+            .map_while(|p| self.get(p).map(|c| (p, c)))
+    }
+
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+fn step((x, y): Pos, d: Point2) -> Option<Pos> {
+    Some((
+        x.checked_add_signed(d.x.try_into().ok()?)?,
+        y.checked_add_signed(d.y.try_into().ok()?)?,
+    ))
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Pos) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, p: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", p, width, height))
+    }
+}
+
+/// Renders the grid one row per line, with no separator between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for c in self.row(y) {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(s: &str) -> Result<Grid<char>> {
+        Self::parse(s, Ok)
+    }
+}
+
+impl Grid<u32> {
+    pub fn parse_digits(s: &str) -> Result<Grid<u32>> {
+        Self::parse(s, |c| c.to_digit(10).ok_or(anyhow!("{} is not a digit", c)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let grid = Grid::parse_digits("123\n456\n")?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get((0, 1)), Some(&4));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(
+            Grid::parse_digits("12\n3x\n").unwrap_err().to_string(),
+            "input:2:2: x is not a digit"
+        );
+        assert_eq!(
+            Grid::parse_chars("ab\nc\n").unwrap_err().to_string(),
+            "input:2:1: row has 1 cells, expected 2"
+        );
+        let grid = Grid::parse_chars("ab\r\ncd\r\n\r\n\n")?;
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(
+            Grid::parse_chars("ab\n\ncd\n").unwrap_err().to_string(),
+            "input:2:1: row has 0 cells, expected 2"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbours() -> Result<()> {
+        let grid = Grid::parse_chars("abc\ndef\nghi\n")?;
+        let cells = |ps: Vec<Pos>| ps.into_iter().map(|p| grid[p]).collect::<String>();
+        assert_eq!(cells(grid.neighbours4((0, 0)).collect()), "bd");
+        assert_eq!(cells(grid.neighbours4((1, 1)).collect()), "bfhd");
+        assert_eq!(cells(grid.neighbours8((1, 1)).collect()), "bcfihgda");
+        assert_eq!(cells(grid.neighbours8((2, 2)).collect()), "fhe");
+        Ok(())
+    }
+
+    #[test]
+    fn test_lines() -> Result<()> {
+        let grid = Grid::parse_chars("abc\ndef\nghi\n")?;
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(grid.column(2).collect::<String>(), "cfi");
+        let ray = |p, d| grid.ray(p, d).map(|(_, c)| c).collect::<String>();
+        assert_eq!(ray((0, 1), Direction4::Right.unit()), "ef");
+        assert_eq!(ray((2, 2), Direction8::NW.unit()), "ea");
+        assert_eq!(ray((1, 0), Direction4::Up.unit()), "");
+        assert_eq!(ray((0, 0), Point2::new(2, 1)), "f");
+        assert_eq!(grid.position(|c| *c == 'h'), Some((1, 2)));
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside the 3x2 grid")]
+    fn test_column_outside() {
+        let grid = Grid::new(3, 2, '.');
+        let _ = grid.column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "would never reach the edge")]
+    fn test_ray_without_step() {
+        let grid = Grid::new(3, 2, '.');
+        let _ = grid.ray((1, 1), Point2::ORIGIN);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::parse::ParseError;
+use anyhow::{Context, Result};
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Reads the whole file at `path` into a string, or the whole of stdin if `path` is `-`.
+pub fn read_string<P: AsRef<Path>>(path: P) -> Result<String> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut s = String::new();
+        std::io::stdin()
+            .read_to_string(&mut s)
+            .context("cannot read stdin")?;
+        return Ok(s);
+    }
+    std::fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))
+}
+
+/// Reads the file at `path` as a list of lines.
+pub fn read_lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
+    Ok(lines(&read_string(path)?))
+}
+
+/// Reads the file at `path` as groups of lines separated by blank lines.
+pub fn read_blocks<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<String>>> {
+    Ok(blocks(&read_string(path)?))
+}
+
+/// Reads the file at `path` as a grid of single-digit numbers.
+pub fn read_digit_grid<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<u32>>> {
+    digit_grid(&read_string(path)?)
+}
+
+/// Reads the file at `path` as a grid of characters.
+pub fn read_char_grid<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<char>>> {
+    Ok(char_grid(&read_string(path)?))
+}
+
+/// Reads the file at `path` parsing every non-empty line with `T::from_str`.
+pub fn read_parsed<T, P>(path: P) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+    P: AsRef<Path>,
+{
+    parse_lines(&read_string(path)?)
+}
+
+pub fn lines(s: &str) -> Vec<String> {
+    s.lines().map(String::from).collect()
+}
+
+pub fn blocks(s: &str) -> Vec<Vec<String>> {
+    lines(s)
+        .split(String::is_empty)
+        .filter(|b| !b.is_empty())
+        .map(Vec::from)
+        .collect()
+}
+
+/// The blocks of `s` separated by blank lines, as slices of it.
+pub fn split_blocks(s: &str) -> impl Iterator<Item = &str> {
+    s.split("\n\n")
+        .map(|b| b.trim_matches('\n'))
+        .filter(|b| !b.is_empty())
+}
+
+pub fn digit_grid(s: &str) -> Result<Vec<Vec<u32>>> {
+    s.lines()
+        .map(|l| {
+            l.char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10).ok_or_else(|| {
+                        let token = &l[i..i + c.len_utf8()];
+                        ParseError::new(s, token, format!("{} is not a digit", c)).into()
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn char_grid(s: &str) -> Vec<Vec<char>> {
+    s.lines().map(|l| l.chars().collect()).collect()
+}
+
+pub fn parse_lines<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    s.lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            l.parse()
+                .map_err(|e: T::Err| ParseError::locate(e.into(), s, l).into())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_string_missing() {
+        assert_eq!(
+            read_string("no/such/input.txt").unwrap_err().to_string(),
+            "cannot read no/such/input.txt"
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        assert_eq!(
+            blocks("1\n2\n\n3\n\n\n4\n"),
+            vec![
+                vec!["1".to_string(), "2".to_string()],
+                vec!["3".to_string()],
+                vec!["4".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_blocks() {
+        assert_eq!(
+            split_blocks("1\n2\n\n3\n\n\n4\n").collect::<Vec<_>>(),
+            vec!["1\n2", "3", "4"]
+        );
+    }
+
+    #[test]
+    fn test_digit_grid() -> Result<()> {
+        assert_eq!(digit_grid("12\n34\n")?, vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(
+            digit_grid("12\n3x\n").unwrap_err().to_string(),
+            "input:2:2: x is not a digit"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_lines() -> Result<()> {
+        assert_eq!(parse_lines::<u32>("1\n2\n\n3\n")?, vec![1, 2, 3]);
+        assert_eq!(
+            parse_lines::<u32>("1\nfoo\n").unwrap_err().to_string(),
+            "input:2:1: invalid digit found in string"
+        );
+        Ok(())
+    }
+}
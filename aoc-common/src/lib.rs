@@ -0,0 +1,10 @@
+pub mod config;
+pub mod direction;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod point;
+pub mod search;
+pub mod solution;
+pub mod visual;
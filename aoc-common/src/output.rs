@@ -0,0 +1,264 @@
+use crate::config::{self, Config};
+use crate::input::read_string;
+use crate::parse::ParseError;
+use crate::solution::{solve, Answers, Part, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use toml::Table;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One JSON object per line.
+    Json,
+    /// A header line followed by one comma-separated line per record.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(anyhow!("invalid format: '{}', expected json or csv", s)),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "day,part,answer,error,elapsed_ms,input";
+
+impl Format {
+    pub fn render(&self, record: &Record) -> String {
+        match self {
+            Format::Json => serde_json::to_string(record).unwrap(),
+            Format::Csv => [
+                record.day.to_string(),
+                record.part.map(|p| p.to_string()).unwrap_or_default(),
+                csv_field(record.answer.as_deref().unwrap_or_default()),
+                csv_field(record.error.as_deref().unwrap_or_default()),
+                record.elapsed_ms.to_string(),
+                csv_field(&record.input),
+            ]
+            .join(","),
+        }
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// The outcome of one part of a day. A day whose input could not be read or parsed has a
+/// single record with no part.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: Option<u32>,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    /// Time spent solving the part, excluding reading and parsing the input.
+    pub elapsed_ms: f64,
+    pub input: String,
+}
+
+impl Record {
+    pub fn new(
+        day: u32,
+        part: Option<Part>,
+        answer: Result<String>,
+        elapsed: Duration,
+        input: &Path,
+    ) -> Record {
+        let (answer, error) = match answer {
+            Ok(a) => (Some(a.trim_matches('\n').to_string()), None),
+            Err(e) => (None, Some(format!("{:#}", e))),
+        };
+        Record {
+            day,
+            part: part.map(|p| p.number()),
+            answer,
+            error,
+            elapsed_ms: elapsed.as_micros() as f64 / 1000.0,
+            input: input.display().to_string(),
+        }
+    }
+}
+
+/// Points a parse error in `result` at the `input` file, printing a diagnostic for it to stderr.
+pub fn diagnose<T>(input: &Path, result: Result<T>) -> Result<T> {
+    result.map_err(|e| match e.downcast::<ParseError>() {
+        Ok(e) => {
+            let e = e.with_file(input);
+            eprint!("{}", e.diagnostic());
+            e.into()
+        }
+        Err(e) => e,
+    })
+}
+
+/// Turns the outcome of solving `day` from `input` into one record per part.
+pub fn records(day: u32, input: &Path, answers: Result<Answers>) -> Vec<Record> {
+    match answers {
+        Ok(answers) => answers
+            .parts
+            .into_iter()
+            .map(|(part, answer, elapsed)| Record::new(day, Some(part), answer, elapsed, input))
+            .collect(),
+        Err(e) => vec![Record::new(day, None, Err(e), Duration::ZERO, input)],
+    }
+}
+
+pub fn print(format: Format, records: &[Record]) {
+    if format == Format::Csv {
+        println!("{}", CSV_HEADER);
+    }
+    for record in records {
+        println!("{}", format.render(record));
+    }
+}
+
+/// The `main` of every day's binary: solves the parts of the input that are solved and prints a
+/// record per part to stdout, as JSON unless `--format csv` is given.
+///
+/// The input is read from the path given as argument, from stdin if it is `-`, or from
+/// `input.txt` in the current directory otherwise. Puzzle parameters are read from the day's
+/// section of `aoc.toml`, like `aoc run` does, and can be overridden with `--set key=value`.
+pub fn run<S: Solution>(day: u32) -> Result<()> {
+    let args = Args::parse(std::env::args().skip(1))
+        .with_context(|| format!("usage: day-{:02} {}", day, Args::USAGE))?;
+    let mut config = Config::load(Config::default_path())?.get(day);
+    config.extend(args.config);
+    let input = read_string(&args.input)?;
+    let answers = diagnose(&args.input, solve::<S>(&input, &config, &Part::ALL));
+
+    let records = records(day, &args.input, answers);
+    print(args.format, &records);
+
+    let failures = records.iter().filter(|r| r.error.is_some()).count();
+    if failures > 0 {
+        bail!("{} of {} parts failed", failures, records.len());
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    format: Format,
+    input: PathBuf,
+    config: Table,
+}
+
+impl Args {
+    const USAGE: &'static str = "[--format json|csv] [--set KEY=VALUE]... [INPUT | -]";
+
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Args> {
+        let mut format = Format::Json;
+        let mut input = None;
+        let mut config = Table::new();
+        while let Some(arg) = args.next() {
+            if arg == "--set" {
+                let (key, value) = config::parse_override(
+                    &args.next().ok_or_else(|| anyhow!("--set needs a value"))?,
+                )?;
+                config.insert(key, value);
+            } else if arg == "--format" {
+                format = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--format needs a value"))?
+                    .parse()?;
+            } else if let Some(f) = arg.strip_prefix("--format=") {
+                format = f.parse()?;
+            } else if arg.starts_with("--") || input.is_some() {
+                bail!("unexpected argument: '{}'", arg);
+            } else {
+                input = Some(PathBuf::from(arg));
+            }
+        }
+        Ok(Args {
+            format,
+            input: input.unwrap_or_else(|| PathBuf::from("input.txt")),
+            config,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::new(
+                10,
+                Some(Part::Two),
+                Ok("\n#.\n.#\n".to_string()),
+                Duration::from_micros(1500),
+                Path::new("input.txt"),
+            ),
+            Record::new(
+                12,
+                None,
+                Err(anyhow!("no path")),
+                Duration::ZERO,
+                Path::new("day-12/input.txt"),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_render_json() {
+        let rendered: Vec<String> = records().iter().map(|r| Format::Json.render(r)).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                r##"{"day":10,"part":2,"answer":"#.\n.#","error":null,"elapsed_ms":1.5,"input":"input.txt"}"##,
+                r#"{"day":12,"part":null,"answer":null,"error":"no path","elapsed_ms":0.0,"input":"day-12/input.txt"}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let rendered: Vec<String> = records().iter().map(|r| Format::Csv.render(r)).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "10,2,\"#.\n.#\",,1.5,input.txt",
+                "12,,,no path,0,day-12/input.txt"
+            ]
+        );
+        assert_eq!(csv_field("say \"hi\", bye"), "\"say \"\"hi\"\", bye\"");
+    }
+
+    #[test]
+    fn test_parse_args() -> Result<()> {
+        let args = |a: &[&str]| Args::parse(a.iter().map(|s| s.to_string()));
+        let expected = |format, input: &str| Args {
+            format,
+            input: PathBuf::from(input),
+            config: Table::new(),
+        };
+        assert_eq!(args(&[])?, expected(Format::Json, "input.txt"));
+        assert_eq!(args(&["--format", "csv", "-"])?, expected(Format::Csv, "-"));
+        assert_eq!(
+            args(&["day-01/input.txt", "--format=json"])?,
+            expected(Format::Json, "day-01/input.txt")
+        );
+        assert!(args(&["--format", "xml"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+        assert_eq!(
+            args(&["--set", "row=10", "--set", "bound=20"])?.config,
+            "row = 10\nbound = 20".parse::<Table>()?
+        );
+        Ok(())
+    }
+}
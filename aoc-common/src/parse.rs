@@ -0,0 +1,265 @@
+use anyhow::Error;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// An error in the puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    file: Option<PathBuf>,
+    /// Byte range of the offending text in the source.
+    start: usize,
+    end: usize,
+    /// 1-based line and column, in characters, of the start of the offending text.
+    line: usize,
+    column: usize,
+    /// Characters of the offending text on its first line, at least 1 for the caret.
+    len: usize,
+    /// The whole line the offending text starts on.
+    text: String,
+}
+
+impl ParseError {
+    /// An error about `token`, which should be a slice of `source`. If it is not, the first
+    /// occurrence of `token` in `source` is blamed, or the whole of `source` failing that.
+    pub fn new<M: Display>(source: &str, token: &str, message: M) -> ParseError {
+        let (start, end) = match offset(source, token).or_else(|| source.find(token)) {
+            Some(start) => (start, start + token.len()),
+            None => (0, source.len()),
+        };
+        ParseError::at(source, start, end, message.to_string())
+    }
+
+    /// Turns any error about `part`, a slice of `source`, into an error about `source`: parse
+    /// errors are moved to their position in it, others blame the whole of `part`.
+    pub fn locate(error: Error, source: &str, part: &str) -> ParseError {
+        match (error.downcast::<ParseError>(), offset(source, part)) {
+            (Ok(e), Some(base)) => ParseError::at(source, base + e.start, base + e.end, e.message),
+            (Ok(e), None) => ParseError::new(source, part, e.message),
+            (Err(e), _) => ParseError::new(source, part, format!("{:#}", e)),
+        }
+    }
+
+    fn at(source: &str, start: usize, end: usize, message: String) -> ParseError {
+        let line_start = source[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let text = source[line_start..].lines().next().unwrap_or("");
+        let line_end = line_start + text.len();
+        ParseError {
+            message,
+            file: None,
+            start,
+            end,
+            line: source[..start].matches('\n').count() + 1,
+            column: source[line_start..start].chars().count() + 1,
+            len: source[start.min(line_end)..end.min(line_end)]
+                .chars()
+                .count()
+                .max(1),
+            text: text.to_string(),
+        }
+    }
+
+    /// Names the file the source was read from.
+    pub fn with_file<P: AsRef<Path>>(mut self, file: P) -> ParseError {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The error as a compiler would print it, with the offending line and a caret under the
+    /// offending text.
+    pub fn diagnostic(&self) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}\n",
+            self.message,
+            gutter,
+            self.file_name(),
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            self.text,
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len)
+        )
+    }
+
+    fn file_name(&self) -> String {
+        self.file
+            .as_ref()
+            .map(|f| f.display().to_string())
+            .unwrap_or_else(|| "input".to_string())
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.file_name(),
+            self.line,
+            self.column,
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// The byte offset of `part` in `source`, if it is a slice of it.
+fn offset(source: &str, part: &str) -> Option<usize> {
+    let start = (part.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+    (start + part.len() <= source.len()).then_some(start)
+}
+
+/// Parses `token`, a slice of `source`, blaming it for any error.
+pub fn parse_token<T>(source: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    token
+        .parse()
+        .map_err(|e: T::Err| ParseError::locate(e.into(), source, token))
+}
+
+/// Splits `s`, a slice of `source`, around the first `separator`, failing if there is none.
+pub fn split_once<'a>(
+    source: &str,
+    s: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::new(source, s, format!("expected '{}'", separator)))
+}
+
+/// Strips `prefix` from `s`, a slice of `source`, failing if `s` does not start with it.
+pub fn strip_prefix<'a>(source: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(source, s, format!("expected '{}'", prefix)))
+}
+
+/// The whitespace-separated words of a string, read one at a time.
+pub struct Words<'a> {
+    source: &'a str,
+    words: std::str::SplitAsciiWhitespace<'a>,
+}
+
+impl<'a> Words<'a> {
+    pub fn new(source: &'a str) -> Words<'a> {
+        Words {
+            source,
+            words: source.split_ascii_whitespace(),
+        }
+    }
+
+    /// The next word, failing at the end of the string if there is none.
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.words.next().ok_or_else(|| {
+            ParseError::new(
+                self.source,
+                &self.source[self.source.len()..],
+                format!("expected {}", expected),
+            )
+        })
+    }
+
+    /// Skips the next word, which must be `word`.
+    pub fn expect(&mut self, word: &str) -> Result<(), ParseError> {
+        let w = self.word(&format!("'{}'", word))?;
+        if w != word {
+            return Err(ParseError::new(
+                self.source,
+                w,
+                format!("expected '{}'", word),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parses the next word.
+    pub fn parse<T>(&mut self, expected: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        parse_token(self.source, self.word(expected)?)
+    }
+
+    /// Fails if there are words left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        match self.words.next() {
+            Some(w) => Err(ParseError::new(self.source, w, "unexpected text")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{anyhow, Result};
+
+    #[test]
+    fn test_position() {
+        let source = "move 1 from 2 to 3\nmove x from 2 to 3\n";
+        let line = source.lines().nth(1).unwrap();
+        let e = parse_token::<u32>(line, &line[5..6]).unwrap_err();
+        assert_eq!(e.to_string(), "input:1:6: invalid digit found in string");
+
+        let e = ParseError::locate(e.into(), source, line).with_file("day-05/input.txt");
+        assert_eq!((e.line(), e.column()), (2, 6));
+        assert_eq!(
+            e.diagnostic(),
+            "error: invalid digit found in string\n \
+             --> day-05/input.txt:2:6\n  \
+             |\n\
+             2 | move x from 2 to 3\n  \
+             |      ^\n"
+        );
+
+        let e = ParseError::locate(anyhow!("bad move"), source, line);
+        assert_eq!(e.to_string(), "input:2:1: bad move");
+        assert!(e.diagnostic().ends_with("\n  | ^^^^^^^^^^^^^^^^^^\n"));
+    }
+
+    #[test]
+    fn test_words() -> Result<()> {
+        let mut words = Words::new("addx 15");
+        words.expect("addx")?;
+        assert_eq!(words.parse::<i32>("a number")?, 15);
+        words.end()?;
+        let mut words = Words::new("addx");
+        words.expect("addx")?;
+        assert_eq!(
+            words.parse::<i32>("a number").unwrap_err().to_string(),
+            "input:1:5: expected a number"
+        );
+        assert_eq!(
+            Words::new("noop 3").expect("addx").unwrap_err().to_string(),
+            "input:1:1: expected 'addx'"
+        );
+        let mut words = Words::new("noop 3");
+        words.word("an instruction")?;
+        assert_eq!(
+            words.end().unwrap_err().to_string(),
+            "input:1:6: unexpected text"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+use crate::grid::Pos;
+use anyhow::{anyhow, Result};
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point, or a vector, on the plane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point, or a vector, in space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    /// Distance moving only along the axes.
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance moving along the axes and diagonally.
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The vector with every coordinate replaced by its sign: one step towards this one.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    /// Distance moving only along the axes.
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Distance moving along the axes and diagonally.
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// The vector with every coordinate replaced by its sign: one step towards this one.
+    pub fn signum(self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+}
+
+// Component-wise arithmetic, and scaling by an integer.
+macro_rules! impl_ops {
+    ($point:ident { $($c:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, n: i64) -> $point {
+                $point { $($c: self.$c * n),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Point2 {
+        Point2::new(x, y)
+    }
+}
+
+impl From<Point2> for (i64, i64) {
+    fn from(p: Point2) -> (i64, i64) {
+        (p.x, p.y)
+    }
+}
+
+impl From<Pos> for Point2 {
+    fn from((x, y): Pos) -> Point2 {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+/// Fails for points with a negative coordinate, which are outside any grid.
+impl TryFrom<Point2> for Pos {
+    type Error = anyhow::Error;
+
+    fn try_from(p: Point2) -> Result<Pos> {
+        Ok((
+            usize::try_from(p.x).map_err(|_| anyhow!("{} is not a grid position", p))?,
+            usize::try_from(p.y).map_err(|_| anyhow!("{} is not a grid position", p))?,
+        ))
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Point3 {
+        Point3::new(x, y, z)
+    }
+}
+
+impl From<Point3> for (i64, i64, i64) {
+    fn from(p: Point3) -> (i64, i64, i64) {
+        (p.x, p.y, p.z)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(a * 3, Point2::new(3, 6));
+        assert_eq!(-a, Point2::new(-1, -2));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(1, -1));
+        assert_eq!(Point3::ORIGIN.manhattan(Point3::new(1, -2, 3)), 6);
+        assert_eq!(Point3::ORIGIN.chebyshev(Point3::new(1, -2, 3)), 3);
+    }
+
+    #[test]
+    fn test_conversions() -> Result<()> {
+        assert_eq!(Point2::from((3i64, 4i64)), Point2::new(3, 4));
+        assert_eq!(<(i64, i64)>::from(Point2::new(3, 4)), (3, 4));
+        assert_eq!(Point2::from((3usize, 4usize)), Point2::new(3, 4));
+        assert_eq!(Pos::try_from(Point2::new(3, 4))?, (3, 4));
+        assert_eq!(
+            Pos::try_from(Point2::new(-1, 4)).unwrap_err().to_string(),
+            "-1,4 is not a grid position"
+        );
+        Ok(())
+    }
+}
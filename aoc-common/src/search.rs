@@ -0,0 +1,312 @@
+use anyhow::Result;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use tracing::debug;
+
+/// A graph to search, with non-negative integer costs on its edges.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes one step from `node`, with the cost of each step.
+    fn neighbours(&self, node: &Self::Node) -> impl IntoIterator<Item = (Self::Node, u64)>;
+
+    /// A lower bound on the cost from `node` to the nearest goal, used by [`astar`]. It must
+    /// never drop by more than the cost of a step, or the path found may not be the cheapest.
+    fn heuristic(&self, _node: &Self::Node) -> u64 {
+        0
+    }
+}
+
+/// A path found by a search, from one of the starts to a goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: u64,
+}
+
+/// Told about each node a search expands, with the nodes it opened up from it: those reached for
+/// the first time, or more cheaply than before. It may stop the search with an error.
+pub type Observer<'a, N> = &'a mut dyn FnMut(&N, &[N]) -> Result<()>;
+
+/// The path with the fewest steps from any of `starts` to a node matching `is_goal`, ignoring
+/// the costs of the edges: its cost is the number of steps.
+pub fn bfs<G: Graph, F: Fn(&G::Node) -> bool>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: F,
+) -> Option<Path<G::Node>> {
+    bfs_observed(graph, starts, is_goal, &mut |_, _| Ok(())).unwrap()
+}
+
+/// [`bfs`], telling `observe` about each node it expands.
+pub fn bfs_observed<G: Graph, F: Fn(&G::Node) -> bool>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: F,
+    observe: Observer<G::Node>,
+) -> Result<Option<Path<G::Node>>> {
+    let mut nodes = Nodes::new();
+    let mut opened = vec![];
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Some(i) = nodes.visit(start, None, 0) {
+            queue.push_back(i);
+        }
+    }
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&nodes.nodes[i]) {
+            debug!(reached = nodes.nodes.len(), "goal found");
+            return Ok(Some(nodes.path(i)));
+        }
+        let (node, cost) = (nodes.nodes[i].clone(), nodes.costs[i] + 1);
+        opened.clear();
+        for (n, _) in graph.neighbours(&node) {
+            if let Some(j) = nodes.visit(n, Some(i), cost) {
+                queue.push_back(j);
+                opened.push(nodes.nodes[j].clone());
+            }
+        }
+        observe(&node, &opened)?;
+    }
+    debug!(reached = nodes.nodes.len(), "no goal reachable");
+    Ok(None)
+}
+
+/// The cheapest path from any of `starts` to a node matching `is_goal`.
+pub fn dijkstra<G: Graph, F: Fn(&G::Node) -> bool>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: F,
+) -> Option<Path<G::Node>> {
+    best_first(graph, starts, is_goal, |_| 0, &mut |_, _| Ok(())).unwrap()
+}
+
+/// The cheapest path from any of `starts` to a node matching `is_goal`, guided by the graph's
+/// heuristic.
+pub fn astar<G: Graph, F: Fn(&G::Node) -> bool>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: F,
+) -> Option<Path<G::Node>> {
+    astar_observed(graph, starts, is_goal, &mut |_, _| Ok(())).unwrap()
+}
+
+/// [`astar`], telling `observe` about each node it expands.
+pub fn astar_observed<G: Graph, F: Fn(&G::Node) -> bool>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: F,
+    observe: Observer<G::Node>,
+) -> Result<Option<Path<G::Node>>> {
+    best_first(graph, starts, is_goal, |n| graph.heuristic(n), observe)
+}
+
+/// The cost of the cheapest path from any of `starts` to every reachable node.
+pub fn distances<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+) -> HashMap<G::Node, u64> {
+    let mut nodes = Nodes::new();
+    explore(&mut nodes, graph, starts, |_| false, |_| 0, &mut |_, _| {
+        Ok(())
+    })
+    .unwrap();
+    nodes
+        .nodes
+        .into_iter()
+        .zip(nodes.costs)
+        .zip(nodes.done)
+        .filter(|(_, done)| *done)
+        .map(|(n, _)| n)
+        .collect()
+}
+
+fn best_first<G: Graph, F: Fn(&G::Node) -> bool, H: Fn(&G::Node) -> u64>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: F,
+    heuristic: H,
+    observe: Observer<G::Node>,
+) -> Result<Option<Path<G::Node>>> {
+    let mut nodes = Nodes::new();
+    let goal = explore(&mut nodes, graph, starts, is_goal, heuristic, observe)?;
+    Ok(goal.map(|i| nodes.path(i)))
+}
+
+// https://en.wikipedia.org/wiki/A*_search_algorithm#Pseudocode, with a priority queue of
+// (estimated total cost, node) and stale entries skipped when popped. Returns the goal reached,
+// if any, leaving every node whose cost is final marked as done. Only fails if `observe` does.
+fn explore<G: Graph, F: Fn(&G::Node) -> bool, H: Fn(&G::Node) -> u64>(
+    nodes: &mut Nodes<G::Node>,
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: F,
+    heuristic: H,
+    observe: Observer<G::Node>,
+) -> Result<Option<usize>> {
+    let mut open = BinaryHeap::new();
+    let mut opened = vec![];
+    for start in starts {
+        let h = heuristic(&start);
+        if let Some(i) = nodes.visit(start, None, 0) {
+            open.push(Reverse((h, i)));
+        }
+    }
+    while let Some(Reverse((_, i))) = open.pop() {
+        if nodes.done[i] {
+            continue;
+        }
+        nodes.done[i] = true;
+        if is_goal(&nodes.nodes[i]) {
+            debug!(reached = nodes.nodes.len(), "goal found");
+            return Ok(Some(i));
+        }
+        let node = nodes.nodes[i].clone();
+        opened.clear();
+        for (n, cost) in graph.neighbours(&node) {
+            let g = nodes.costs[i] + cost;
+            let h = heuristic(&n);
+            if let Some(j) = nodes.improve(n, i, g) {
+                open.push(Reverse((g + h, j)));
+                opened.push(nodes.nodes[j].clone());
+            }
+        }
+        observe(&node, &opened)?;
+    }
+    debug!(reached = nodes.nodes.len(), "no goal reachable");
+    Ok(None)
+}
+
+// The nodes seen so far, numbered in the order they were first reached.
+struct Nodes<N> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<u64>,
+    done: Vec<bool>,
+}
+
+impl<N: Clone + Eq + Hash> Nodes<N> {
+    fn new() -> Nodes<N> {
+        Nodes {
+            index: HashMap::new(),
+            nodes: Vec::new(),
+            parents: Vec::new(),
+            costs: Vec::new(),
+            done: Vec::new(),
+        }
+    }
+
+    // Records `node` the first time it is reached, returning its number; `None` afterwards.
+    fn visit(&mut self, node: N, parent: Option<usize>, cost: u64) -> Option<usize> {
+        if self.index.contains_key(&node) {
+            return None;
+        }
+        let i = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push(node);
+        self.parents.push(parent);
+        self.costs.push(cost);
+        self.done.push(false);
+        Some(i)
+    }
+
+    // Records `node` as reached from `parent` at `cost` unless it was already reached more
+    // cheaply, returning its number if it was.
+    fn improve(&mut self, node: N, parent: usize, cost: u64) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&i) if self.done[i] || self.costs[i] <= cost => None,
+            Some(&i) => {
+                self.parents[i] = Some(parent);
+                self.costs[i] = cost;
+                Some(i)
+            }
+            None => self.visit(node, Some(parent), cost),
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<N> {
+        let mut nodes = vec![self.nodes[goal].clone()];
+        let mut i = goal;
+        while let Some(p) = self.parents[i] {
+            nodes.push(self.nodes[p].clone());
+            i = p;
+        }
+        nodes.reverse();
+        Path {
+            nodes,
+            cost: self.costs[goal],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A line of nodes 0..10, where stepping right costs 1 and jumping 3 to the right costs 2.
+    struct Line;
+
+    impl Graph for Line {
+        type Node = u32;
+
+        fn neighbours(&self, n: &u32) -> impl IntoIterator<Item = (u32, u64)> {
+            [(n + 1, 1), (n + 3, 2)]
+                .into_iter()
+                .filter(|(m, _)| *m < 10)
+        }
+
+        fn heuristic(&self, n: &u32) -> u64 {
+            (9 - *n as u64) / 2
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        assert_eq!(
+            bfs(&Line, [0], |n| *n == 9),
+            Some(Path {
+                nodes: vec![0, 3, 6, 9],
+                cost: 3
+            })
+        );
+        let cheapest = Some(Path {
+            nodes: vec![0, 3, 6, 9],
+            cost: 6,
+        });
+        assert_eq!(dijkstra(&Line, [0], |n| *n == 9), cheapest);
+        assert_eq!(astar(&Line, [0], |n| *n == 9), cheapest);
+        assert_eq!(astar(&Line, [4, 8], |n| *n == 9).map(|p| p.cost), Some(1));
+        assert_eq!(astar(&Line, [0], |n| *n == 10), None);
+    }
+
+    #[test]
+    fn test_observed() -> Result<()> {
+        let mut expanded = vec![];
+        let path = bfs_observed(&Line, [0], |n| *n == 9, &mut |n, opened| {
+            expanded.push((*n, opened.to_vec()));
+            Ok(())
+        })?;
+        assert_eq!(path.map(|p| p.cost), Some(3));
+        assert_eq!(expanded[0], (0, vec![1, 3]));
+        assert_eq!(expanded[1], (1, vec![2, 4]));
+
+        let stopped = astar_observed(&Line, [0], |n| *n == 9, &mut |n, _| {
+            anyhow::ensure!(*n < 3, "too far");
+            Ok(())
+        });
+        assert!(stopped.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_distances() {
+        let distances = distances(&Line, [5]);
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&5], 0);
+        assert_eq!(distances[&7], 2);
+        assert_eq!(distances[&8], 2);
+        assert_eq!(distances.get(&4), None);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::config;
+use anyhow::{anyhow, Result};
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+use toml::Table;
+use tracing::{info_span, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u32> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(n: u32) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(anyhow!("invalid part: {}", n)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A day's puzzle: parses the raw input once and solves both parts from it. Solutions only
+/// compute, without reading files or printing, so that other programs can embed them.
+pub trait Solution {
+    /// The parsed input, shared by the parts as they are solved in parallel.
+    type Input: Sync;
+    /// The puzzle parameters, read from the day's section of `aoc.toml`, or `()` if it has none.
+    type Config: Default + DeserializeOwned + Sync;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// The parts that are solved. A day whose second part is not solved yet lists only the first,
+    /// and its `part2` is never called.
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input, config: &Self::Config) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input, config: &Self::Config) -> Result<Self::Answer2>;
+}
+
+/// The rendered answer, or the error, of every part that was solved, with the time each phase
+/// took.
+#[derive(Debug)]
+pub struct Answers {
+    pub parse_time: Duration,
+    pub parts: Vec<(Part, Result<String>, Duration)>,
+}
+
+/// Parses `input` and solves the requested `parts` with the parameters in `config`, rendering
+/// each answer with `Display`. Parts that are not solved yet are left out. The parts are
+/// independent, so they are solved in parallel, each in a `part` span under the caller's.
+pub fn solve<S: Solution>(input: &str, config: &Table, parts: &[Part]) -> Result<Answers> {
+    let config = config::parse::<S::Config>(config)?;
+    let start = Instant::now();
+    let parsed = info_span!("parse").in_scope(|| S::parse(input))?;
+    let parse_time = start.elapsed();
+    let parent = Span::current();
+    let parts = parts
+        .par_iter()
+        .filter(|part| S::PARTS.contains(part))
+        .map(|part| {
+            let _span = info_span!(parent: &parent, "part", part = part.number()).entered();
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&parsed, &config).map(|a| a.to_string()),
+                Part::Two => S::part2(&parsed, &config).map(|a| a.to_string()),
+            };
+            (*part, answer, start.elapsed())
+        })
+        .collect();
+    Ok(Answers { parse_time, parts })
+}
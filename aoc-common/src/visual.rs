@@ -0,0 +1,99 @@
+use crate::config;
+use crate::grid::Grid;
+use crate::solution::{Part, Solution};
+use anyhow::{bail, Result};
+use std::fmt::{self, Display};
+use toml::Table;
+
+/// A 24-bit colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const GREY: Rgb = Rgb(90, 90, 90);
+    pub const WHITE: Rgb = Rgb(230, 230, 230);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 80);
+    pub const BLUE: Rgb = Rgb(60, 120, 220);
+    pub const YELLOW: Rgb = Rgb(240, 200, 60);
+    pub const BROWN: Rgb = Rgb(150, 100, 60);
+
+    /// The colour `t` of the way from `self` to `other`, with `t` in `0.0..=1.0`.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// A character in a colour: what frames are drawn with, as text in a terminal or as a block of
+/// pixels in an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub ch: char,
+    pub colour: Rgb,
+}
+
+impl Glyph {
+    pub const BLANK: Glyph = Glyph::new(' ', Rgb::BLACK);
+
+    pub const fn new(ch: char, colour: Rgb) -> Glyph {
+        Glyph { ch, colour }
+    }
+}
+
+impl Display for Glyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ch)
+    }
+}
+
+/// A picture of a simulation at one of its steps.
+pub type Frame = Grid<Glyph>;
+
+/// Receives the frames of a simulation as it runs, each with a caption saying what happened in
+/// its step, and may stop it with an error.
+pub type Frames<'a> = &'a mut dyn FnMut(&Frame, &str) -> Result<()>;
+
+/// A solution whose simulation can be watched.
+pub trait Visualize: Solution {
+    /// Runs the simulation of `part`, passing a picture of each step to `frames`. Every frame
+    /// has the same size.
+    fn visualize(
+        input: &Self::Input,
+        config: &Self::Config,
+        part: Part,
+        frames: Frames,
+    ) -> Result<()>;
+}
+
+/// Parses `input` and runs the simulation of `part` with the parameters in `config`, if the part
+/// is solved.
+pub fn visualize<S: Visualize>(
+    input: &str,
+    config: &Table,
+    part: Part,
+    frames: Frames,
+) -> Result<()> {
+    if !S::PARTS.contains(&part) {
+        bail!("part {} is not solved yet", part);
+    }
+    let config = config::parse::<S::Config>(config)?;
+    S::visualize(&S::parse(input)?, &config, part, frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(Rgb::BLACK.mix(Rgb(200, 100, 50), 0.0), Rgb::BLACK);
+        assert_eq!(Rgb::BLACK.mix(Rgb(200, 100, 50), 0.5), Rgb(100, 50, 25));
+        assert_eq!(Rgb::BLACK.mix(Rgb(200, 100, 50), 1.0), Rgb(200, 100, 50));
+    }
+}
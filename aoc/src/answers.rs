@@ -0,0 +1,101 @@
+use anyhow::Result;
+use aoc_common::config;
+use aoc_common::input::read_string;
+use aoc_common::solution::Part;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use toml::Table;
+
+#[derive(Debug, Default, Deserialize)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+    /// Parameters the answers were computed with, on top of `aoc.toml`.
+    config: Option<Table>,
+}
+
+/// Known-good answers for every day's real input, as recorded in `answers.toml`, or for its
+/// example, as recorded in `examples.toml`.
+#[derive(Debug)]
+pub struct Answers {
+    days: BTreeMap<u32, DayAnswers>,
+}
+
+impl Answers {
+    pub fn default_path() -> PathBuf {
+        crate::workspace_root().join("answers.toml")
+    }
+
+    pub fn examples_path() -> PathBuf {
+        crate::workspace_root().join("examples.toml")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Answers> {
+        Self::parse(&read_string(path)?)
+    }
+
+    pub fn parse(s: &str) -> Result<Answers> {
+        Ok(Answers {
+            days: config::parse_days(s)?,
+        })
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        let answers = self.days.get(&day)?;
+        match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        }
+    }
+
+    pub fn config(&self, day: u32) -> Option<&Table> {
+        self.days.get(&day)?.config.as_ref()
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.days.keys().copied()
+    }
+}
+
+/// Compares answers ignoring the blank lines around multi-line answers, like day 10's screen.
+pub fn same_answer(expected: &str, actual: &str) -> bool {
+    expected.trim_matches('\n') == actual.trim_matches('\n')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let answers = Answers::parse(
+            r#"
+[1]
+part1 = "42"
+
+[10]
+part2 = """
+#.
+.#
+"""
+
+[15]
+part1 = "26"
+config = { row = 10 }
+"#,
+        )?;
+
+        assert_eq!(answers.get(1, Part::One), Some("42"));
+        assert_eq!(answers.get(1, Part::Two), None);
+        assert_eq!(answers.get(2, Part::One), None);
+        assert!(same_answer(
+            answers.get(10, Part::Two).unwrap(),
+            "\n#.\n.#\n"
+        ));
+        assert_eq!(answers.config(1), None);
+        assert_eq!(answers.config(15), Some(&"row = 10".parse::<Table>()?));
+        assert_eq!(answers.days().collect::<Vec<u32>>(), vec![1, 10, 15]);
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::Result;
+use aoc_common::solution::{solve, Answers, Part, Solution};
+use aoc_common::visual::{visualize, Frames};
+use std::path::PathBuf;
+use toml::Table;
+
+/// Plays a part's simulation into the frames, like [`visualize`].
+pub type Visualizer = fn(&str, &Table, Part, Frames) -> Result<()>;
+
+pub struct Day {
+    pub number: u32,
+    pub solve: fn(&str, &Table, &[Part]) -> Result<Answers>,
+    /// The parts that are solved, which `solve` sticks to.
+    pub parts: &'static [Part],
+    /// Runs a part's simulation frame by frame, for the days that have something to watch.
+    pub visualize: Option<Visualizer>,
+}
+
+impl Day {
+    pub fn default_input(&self) -> PathBuf {
+        self.dir().join("input.txt")
+    }
+
+    pub fn example_input(&self) -> PathBuf {
+        self.dir().join("example.txt")
+    }
+
+    /// The day's crate.
+    pub fn dir(&self) -> PathBuf {
+        crate::workspace_root().join(format!("day-{:02}", self.number))
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solve: solve::<day_01::Day01>,
+        parts: day_01::Day01::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 2,
+        solve: solve::<day_02::Day02>,
+        parts: day_02::Day02::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 3,
+        solve: solve::<day_03::Day03>,
+        parts: day_03::Day03::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 4,
+        solve: solve::<day_04::Day04>,
+        parts: day_04::Day04::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 5,
+        solve: solve::<day_05::Day05>,
+        parts: day_05::Day05::PARTS,
+        visualize: Some(visualize::<day_05::Day05>),
+    },
+    Day {
+        number: 6,
+        solve: solve::<day_06::Day06>,
+        parts: day_06::Day06::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 7,
+        solve: solve::<day_07::Day07>,
+        parts: day_07::Day07::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 8,
+        solve: solve::<day_08::Day08>,
+        parts: day_08::Day08::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 9,
+        solve: solve::<day_09::Day09>,
+        parts: day_09::Day09::PARTS,
+        visualize: Some(visualize::<day_09::Day09>),
+    },
+    Day {
+        number: 10,
+        solve: solve::<day_10::Day10>,
+        parts: day_10::Day10::PARTS,
+        visualize: Some(visualize::<day_10::Day10>),
+    },
+    Day {
+        number: 11,
+        solve: solve::<day_11::Day11>,
+        parts: day_11::Day11::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 12,
+        solve: solve::<day_12::Day12>,
+        parts: day_12::Day12::PARTS,
+        visualize: Some(visualize::<day_12::Day12>),
+    },
+    Day {
+        number: 13,
+        solve: solve::<day_13::Day13>,
+        parts: day_13::Day13::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 14,
+        solve: solve::<day_14::Day14>,
+        parts: day_14::Day14::PARTS,
+        visualize: Some(visualize::<day_14::Day14>),
+    },
+    Day {
+        number: 15,
+        solve: solve::<day_15::Day15>,
+        parts: day_15::Day15::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 17,
+        solve: solve::<day_17::Day17>,
+        parts: day_17::Day17::PARTS,
+        visualize: Some(visualize::<day_17::Day17>),
+    },
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,397 @@
+use anyhow::{anyhow, Result};
+use aoc::days::{self, Day};
+use aoc_common::solution::Part;
+use aoc_common::visual::{Frame, Frames, Rgb};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListState, Paragraph};
+use ratatui::DefaultTerminal;
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use toml::Table;
+
+// How many frames a simulation may run ahead of the one on screen.
+const AHEAD: usize = 64;
+// How many frames are kept for stepping back through.
+const HISTORY: usize = 2000;
+
+const PICKER_HELP: &str = "↑/↓ choose · enter watch · q quit";
+const PLAYER_HELP: &str =
+    "space play/pause · n/p next/previous frame · r/e first/last frame · +/- speed · arrows scroll · esc back · q quit";
+
+/// Reads the input and the puzzle parameters of a day.
+pub type Load<'a> = &'a dyn Fn(&Day) -> Result<(String, Table)>;
+
+/// Lets the user pick one of the days' simulations and play it in the terminal, stepping through
+/// it frame by frame if they like, until they quit.
+pub fn run(load: Load) -> Result<()> {
+    let mut terminal = ratatui::init();
+    let result = App::new(load).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+struct App<'a> {
+    load: Load<'a>,
+    /// The day and part of every simulation there is to watch.
+    choices: Vec<(&'static Day, Part)>,
+    list: ListState,
+    /// Why the last simulation picked could not start.
+    error: Option<String>,
+    player: Option<Player>,
+}
+
+impl App<'_> {
+    fn new(load: Load) -> App {
+        let choices = days::DAYS
+            .iter()
+            .filter(|day| day.visualize.is_some())
+            .flat_map(|day| day.parts.iter().map(move |part| (day, *part)))
+            .collect();
+        App {
+            load,
+            choices,
+            list: ListState::default().with_selected(Some(0)),
+            error: None,
+            player: None,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        let mut next_tick = Instant::now();
+        loop {
+            terminal.draw(|screen| self.draw(screen))?;
+            let timeout = next_tick.saturating_duration_since(Instant::now());
+            if event::poll(timeout)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press && !self.key(key.code) {
+                        return Ok(());
+                    }
+                }
+            }
+            if Instant::now() >= next_tick {
+                if let Some(player) = &mut self.player {
+                    player.tick();
+                }
+                next_tick = Instant::now() + self.player.as_ref().map_or(IDLE, Player::delay);
+            }
+        }
+    }
+
+    // Handles a key press, returning whether to carry on.
+    fn key(&mut self, code: KeyCode) -> bool {
+        if code == KeyCode::Char('q') {
+            return false;
+        }
+        match &mut self.player {
+            Some(player) => match code {
+                KeyCode::Esc | KeyCode::Backspace => self.player = None,
+                code => player.key(code),
+            },
+            None => match code {
+                KeyCode::Esc => return false,
+                KeyCode::Up => self.list.select_previous(),
+                KeyCode::Down => self.list.select_next(),
+                KeyCode::Enter => self.start(),
+                _ => {}
+            },
+        }
+        true
+    }
+
+    fn start(&mut self) {
+        let Some(&(day, part)) = self.list.selected().and_then(|i| self.choices.get(i)) else {
+            return;
+        };
+        match (self.load)(day) {
+            Ok((input, config)) => {
+                let visualize = day.visualize.unwrap();
+                let title = format!("Day {}, part {}", day.number, part);
+                self.player = Some(Player::start(title, move |frames| {
+                    visualize(&input, &config, part, frames)
+                }));
+                self.error = None;
+            }
+            Err(e) => self.error = Some(format!("day {}: {:#}", day.number, e)),
+        }
+    }
+
+    fn draw(&mut self, screen: &mut ratatui::Frame) {
+        if let Some(player) = &mut self.player {
+            return player.draw(screen);
+        }
+        let [list_area, error_area, help_area] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(screen.area());
+        let items = self
+            .choices
+            .iter()
+            .map(|(day, part)| format!("Day {:>2}, part {}", day.number, part));
+        let list = List::new(items)
+            .block(Block::bordered().title(" Simulations "))
+            .highlight_symbol("> ")
+            .highlight_style(Style::new().reversed());
+        screen.render_stateful_widget(list, list_area, &mut self.list);
+        if let Some(error) = &self.error {
+            screen.render_widget(Paragraph::new(error.as_str()).red(), error_area);
+        }
+        screen.render_widget(Paragraph::new(PICKER_HELP).dark_gray(), help_area);
+    }
+}
+
+// How often the screen is redrawn when no simulation is playing.
+const IDLE: Duration = Duration::from_millis(100);
+
+/// Plays the frames of a simulation running on another thread, keeping the latest ones to step
+/// back through.
+struct Player {
+    title: String,
+    frames: Receiver<(Frame, String)>,
+    simulation: Option<JoinHandle<Result<()>>>,
+    /// The latest frames received, with their captions, oldest first.
+    history: VecDeque<(Frame, String)>,
+    /// How many frames were dropped from the front of `history` to keep it short.
+    dropped: usize,
+    /// The frame on screen, in `history`.
+    at: usize,
+    playing: bool,
+    fps: u32,
+    /// The first column and row of the frame on screen.
+    scroll: (usize, usize),
+    /// How the simulation ended, once it has.
+    outcome: Option<Result<(), String>>,
+}
+
+impl Player {
+    // Runs `simulate` on its own thread, which stops when the player is dropped.
+    fn start<F>(title: String, simulate: F) -> Player
+    where
+        F: FnOnce(Frames) -> Result<()> + Send + 'static,
+    {
+        let (sender, frames) = mpsc::sync_channel(AHEAD);
+        let simulation = thread::spawn(move || {
+            simulate(&mut |frame, caption| {
+                sender
+                    .send((frame.clone(), caption.to_string()))
+                    .map_err(|_| anyhow!("no one is watching"))
+            })
+        });
+        Player {
+            title,
+            frames,
+            simulation: Some(simulation),
+            history: VecDeque::new(),
+            dropped: 0,
+            at: 0,
+            playing: true,
+            fps: 10,
+            scroll: (0, 0),
+            outcome: None,
+        }
+    }
+
+    fn delay(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    fn tick(&mut self) {
+        if self.playing || self.history.is_empty() {
+            self.next();
+        }
+    }
+
+    fn key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Char('n') => {
+                self.playing = false;
+                self.next();
+            }
+            KeyCode::Char('p') => {
+                self.playing = false;
+                self.at = self.at.saturating_sub(1);
+            }
+            KeyCode::Char('r') => self.at = 0,
+            KeyCode::Char('e') => self.at = self.history.len().saturating_sub(1),
+            KeyCode::Char('+') => self.fps = (self.fps * 2).min(480),
+            KeyCode::Char('-') => self.fps = (self.fps / 2).max(1),
+            KeyCode::Up => self.scroll.1 = self.scroll.1.saturating_sub(1),
+            KeyCode::Down => self.scroll.1 += 1,
+            KeyCode::Left => self.scroll.0 = self.scroll.0.saturating_sub(1),
+            KeyCode::Right => self.scroll.0 += 1,
+            KeyCode::PageUp => self.scroll.1 = self.scroll.1.saturating_sub(PAGE),
+            KeyCode::PageDown => self.scroll.1 += PAGE,
+            _ => {}
+        }
+    }
+
+    // Moves to the next frame, from the history or else from the simulation if it has one ready.
+    // Returns whether there was one.
+    fn next(&mut self) -> bool {
+        if self.at + 1 < self.history.len() {
+            self.at += 1;
+            return true;
+        }
+        match self.frames.try_recv() {
+            Ok(frame) => {
+                self.history.push_back(frame);
+                if self.history.len() > HISTORY {
+                    self.history.pop_front();
+                    self.dropped += 1;
+                }
+                self.at = self.history.len() - 1;
+                true
+            }
+            Err(TryRecvError::Empty) => false,
+            Err(TryRecvError::Disconnected) => {
+                if let Some(simulation) = self.simulation.take() {
+                    self.outcome = Some(match simulation.join() {
+                        Ok(Ok(())) => Ok(()),
+                        Ok(Err(e)) => Err(format!("{:#}", e)),
+                        Err(_) => Err("the simulation panicked".to_string()),
+                    });
+                }
+                self.playing = false;
+                false
+            }
+        }
+    }
+
+    fn draw(&mut self, screen: &mut ratatui::Frame) {
+        let [frame_area, caption_area, status_area, help_area] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(screen.area());
+        let block = Block::bordered().title(format!(" {} ", self.title));
+        let inner = block.inner(frame_area);
+        screen.render_widget(block, frame_area);
+
+        if let Some((frame, caption)) = self.history.get(self.at) {
+            // Keeps as much of the frame in view as fits.
+            self.scroll.0 = self
+                .scroll
+                .0
+                .min(frame.width().saturating_sub(inner.width as usize));
+            self.scroll.1 =
+                (self.scroll.1).min(frame.height().saturating_sub(inner.height as usize));
+            screen.render_widget(Paragraph::new(lines(frame, self.scroll, inner)), inner);
+            screen.render_widget(Paragraph::new(caption.as_str()), caption_area);
+        }
+        screen.render_widget(self.status(), status_area);
+        screen.render_widget(Paragraph::new(PLAYER_HELP).dark_gray(), help_area);
+    }
+
+    fn status(&self) -> Paragraph<'static> {
+        let state = match &self.outcome {
+            Some(Err(e)) => return Paragraph::new(format!("failed: {}", e)).red(),
+            Some(Ok(())) if self.at + 1 == self.history.len() => "finished".to_string(),
+            _ if self.playing => format!("playing at {} fps", self.fps),
+            _ => "paused".to_string(),
+        };
+        // Frames dropped from the history still count, so a frame keeps its number.
+        Paragraph::new(format!(
+            "frame {} of {} · {}",
+            self.dropped + self.at + 1,
+            self.dropped + self.history.len(),
+            state
+        ))
+    }
+}
+
+// Rows scrolled by a page.
+const PAGE: usize = 20;
+
+// The part of `frame` from `scroll` that fits in `area`, with a span for each run of glyphs in the
+// same colour.
+fn lines(frame: &Frame, (x0, y0): (usize, usize), area: Rect) -> Vec<Line<'static>> {
+    let x1 = frame.width().min(x0 + area.width as usize);
+    let y1 = frame.height().min(y0 + area.height as usize);
+    (y0..y1)
+        .map(|y| {
+            let mut spans: Vec<(Rgb, String)> = vec![];
+            for glyph in frame.row(y).skip(x0).take(x1 - x0) {
+                match spans.last_mut() {
+                    Some((colour, text)) if *colour == glyph.colour => text.push(glyph.ch),
+                    _ => spans.push((glyph.colour, glyph.ch.to_string())),
+                }
+            }
+            let spans: Vec<Span> = spans
+                .into_iter()
+                .map(|(Rgb(r, g, b), text)| {
+                    Span::styled(text, Style::new().fg(Color::Rgb(r, g, b)))
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::visual::Glyph;
+
+    // Waits for the next frame, returning false once the simulation is over.
+    fn next(player: &mut Player) -> bool {
+        loop {
+            if player.next() {
+                return true;
+            }
+            if player.outcome.is_some() {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    fn caption(player: &Player) -> &str {
+        &player.history[player.at].1
+    }
+
+    #[test]
+    fn test_player() {
+        let mut player = Player::start("test".to_string(), |frames| {
+            let frame = Frame::new(1, 1, Glyph::BLANK);
+            for i in 0..HISTORY + 5 {
+                frames(&frame, &i.to_string())?;
+            }
+            Err(anyhow!("out of rocks"))
+        });
+        assert!(next(&mut player) && next(&mut player));
+        assert_eq!(caption(&player), "1");
+        player.key(KeyCode::Char('p'));
+        player.key(KeyCode::Char('p'));
+        assert_eq!(caption(&player), "0");
+        assert!(next(&mut player));
+        assert_eq!(caption(&player), "1");
+
+        while next(&mut player) {}
+        assert_eq!(player.outcome, Some(Err("out of rocks".to_string())));
+        assert_eq!(player.history.len(), HISTORY);
+        assert_eq!(caption(&player), (HISTORY + 4).to_string());
+        player.key(KeyCode::Char('r'));
+        assert_eq!(caption(&player), "5");
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut frame = Frame::new(4, 3, Glyph::BLANK);
+        frame[(1, 1)] = Glyph::new('#', Rgb::RED);
+        frame[(2, 1)] = Glyph::new('#', Rgb::RED);
+        let lines = lines(&frame, (1, 1), Rect::new(0, 0, 2, 5));
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].spans.len(), 1);
+        assert_eq!(lines[0].spans[0].content, "##");
+        assert_eq!(lines[1].spans[0].content, "  ");
+    }
+}
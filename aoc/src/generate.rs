@@ -0,0 +1,480 @@
+use anyhow::{bail, Result};
+use aoc_common::config;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::fmt::Write;
+use toml::Table;
+
+/// A random, valid puzzle input for `day`, always the same for the same `size` and `seed`.
+/// `size` is roughly the number of items in the input: elves, lines, moves, grid columns...
+/// `config` holds the day's puzzle parameters, which the input suits: only day 15's search area
+/// depends on them.
+pub fn generate(day: u32, size: usize, seed: u64, config: &Table) -> Result<String> {
+    if size == 0 {
+        bail!("the size must be at least 1");
+    }
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+    Ok(match day {
+        1 => calories(rng, size),
+        2 => strategy_guide(rng, size),
+        3 => rucksacks(rng, size),
+        4 => section_pairs(rng, size),
+        5 => rearrangement(rng, size),
+        6 => datastream(rng, size),
+        7 => terminal_output(rng, size),
+        8 => tree_grid(rng, size),
+        9 => rope_moves(rng, size),
+        10 => program(rng, size),
+        11 => monkeys(rng, size),
+        12 => heightmap(rng, size),
+        13 => packet_pairs(rng, size),
+        14 => rock_paths(rng, size),
+        15 => sensor_reports(rng, size, config::parse::<day_15::Config>(config)?.bound),
+        17 => jet_pattern(rng, size),
+        _ => bail!("no generator for day {}", day),
+    })
+}
+
+fn calories(rng: &mut impl Rng, elves: usize) -> String {
+    (0..elves)
+        .map(|_| {
+            (0..rng.random_range(1..=15))
+                .map(|_| format!("{}\n", rng.random_range(1000..=60000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn strategy_guide(rng: &mut impl Rng, rounds: usize) -> String {
+    (0..rounds)
+        .map(|_| {
+            let opponent = ['A', 'B', 'C'].choose(rng).unwrap();
+            let column = ['X', 'Y', 'Z'].choose(rng).unwrap();
+            format!("{} {}\n", opponent, column)
+        })
+        .collect()
+}
+
+// Groups of three rucksacks sharing only their badge, each with one item in both compartments.
+fn rucksacks(rng: &mut impl Rng, rucksacks: usize) -> String {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut out = String::new();
+    for _ in 0..rucksacks.div_ceil(3) {
+        items.shuffle(rng);
+        let badge = items[0];
+        // Each elf of the group picks from its own 17 items.
+        for own in items[1..].chunks(17) {
+            let (shared, left_only, right_only) = (own[0], &own[1..9], &own[9..]);
+            let half = rng.random_range(2..=16);
+            let mut left = vec![shared, badge];
+            left.extend((2..half).map(|_| *left_only.choose(rng).unwrap()));
+            let mut right = vec![shared];
+            right.extend((1..half).map(|_| *right_only.choose(rng).unwrap()));
+            left.shuffle(rng);
+            right.shuffle(rng);
+            writeln!(
+                out,
+                "{}{}",
+                String::from_iter(left),
+                String::from_iter(right)
+            )
+            .unwrap();
+        }
+    }
+    out
+}
+
+fn section_pairs(rng: &mut impl Rng, pairs: usize) -> String {
+    let mut range = || {
+        let from = rng.random_range(1..=99);
+        format!("{}-{}", from, rng.random_range(from..=99))
+    };
+    (0..pairs)
+        .map(|_| format!("{},{}\n", range(), range()))
+        .collect()
+}
+
+// Nine stacks, and moves that only ever take as many crates as the stack holds.
+fn rearrangement(rng: &mut impl Rng, moves: usize) -> String {
+    const STACKS: usize = 9;
+    let max_height = (moves / 20).max(8);
+    let mut heights: Vec<usize> = (0..STACKS)
+        .map(|_| rng.random_range(0..=max_height))
+        .collect();
+    if heights.iter().all(|h| *h == 0) {
+        heights[0] = 1;
+    }
+
+    let mut out = String::new();
+    for level in (0..*heights.iter().max().unwrap()).rev() {
+        let row = heights
+            .iter()
+            .map(|h| match *h > level {
+                true => format!("[{}]", rng.random_range('A'..='Z')),
+                false => "   ".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(out, "{}", row.trim_end()).unwrap();
+    }
+    let labels = (1..=STACKS).map(|i| format!(" {} ", i)).collect::<Vec<_>>();
+    writeln!(out, "{}\n", labels.join(" ").trim_end()).unwrap();
+
+    for _ in 0..moves {
+        let from = rng.random_range(0..STACKS);
+        if heights[from] == 0 {
+            continue;
+        }
+        let to = (from + rng.random_range(1..STACKS)) % STACKS;
+        let n = rng.random_range(1..=heights[from]);
+        heights[from] -= n;
+        heights[to] += n;
+        writeln!(out, "move {} from {} to {}", n, from + 1, to + 1).unwrap();
+    }
+    out
+}
+
+// Characters from too small an alphabet to hold a start-of-message marker, until one is planted.
+fn datastream(rng: &mut impl Rng, len: usize) -> String {
+    let len = len.max(14);
+    let mut stream: Vec<char> = (0..len).map(|_| rng.random_range('a'..='h')).collect();
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    letters.shuffle(rng);
+    let at = rng.random_range(0..=len - 14);
+    stream[at..at + 14].copy_from_slice(&letters[..14]);
+    format!("{}\n", String::from_iter(stream))
+}
+
+// A directory tree that tends to grow deep, listed depth first. The files add up to between 40
+// and 70 million, so that the update fits on the disk once a directory is deleted.
+fn terminal_output(rng: &mut impl Rng, entries: usize) -> String {
+    struct Dir {
+        name: String,
+        subdirs: Vec<usize>,
+        files: Vec<(String, u64)>,
+    }
+    let mut dirs = vec![Dir {
+        name: "/".to_string(),
+        subdirs: vec![],
+        files: vec![],
+    }];
+    for _ in 0..entries {
+        let parent = match rng.random_bool(0.5) {
+            true => dirs.len() - 1,
+            false => rng.random_range(0..dirs.len()),
+        };
+        let name = loop {
+            let name = name(rng);
+            let taken = |d: &Dir| {
+                d.subdirs.iter().any(|s| dirs[*s].name == name)
+                    || d.files.iter().any(|f| f.0 == name)
+            };
+            if !taken(&dirs[parent]) {
+                break name;
+            }
+        };
+        if rng.random_bool(0.25) {
+            let i = dirs.len();
+            dirs[parent].subdirs.push(i);
+            dirs.push(Dir {
+                name,
+                subdirs: vec![],
+                files: vec![],
+            });
+        } else {
+            dirs[parent].files.push((name, rng.random_range(1..=1000)));
+        }
+    }
+    let total: u64 = rng.random_range(40_000_000..70_000_000);
+    let weights: u64 = dirs.iter().flat_map(|d| &d.files).map(|f| f.1).sum();
+
+    // Directories to enter, or `None` to leave one.
+    let mut stack = vec![Some(0)];
+    let mut lines = vec![];
+    while let Some(step) = stack.pop() {
+        let Some(i) = step else {
+            lines.push("$ cd ..".to_string());
+            continue;
+        };
+        let dir = &dirs[i];
+        lines.push(format!("$ cd {}", dir.name));
+        lines.push("$ ls".to_string());
+        for d in &dir.subdirs {
+            lines.push(format!("dir {}", dirs[*d].name));
+        }
+        for (name, weight) in &dir.files {
+            lines.push(format!("{} {}", (weight * total / weights).max(1), name));
+        }
+        for d in dir.subdirs.iter().rev() {
+            stack.extend([None, Some(*d)]);
+        }
+    }
+    while lines.last().is_some_and(|l| l == "$ cd ..") {
+        lines.pop();
+    }
+    lines.into_iter().map(|l| l + "\n").collect()
+}
+
+fn name(rng: &mut impl Rng) -> String {
+    (0..rng.random_range(1..=8))
+        .map(|_| rng.random_range('a'..='z'))
+        .collect()
+}
+
+fn tree_grid(rng: &mut impl Rng, side: usize) -> String {
+    (0..side)
+        .map(|_| {
+            let row: String = (0..side).map(|_| rng.random_range('0'..='9')).collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+fn rope_moves(rng: &mut impl Rng, moves: usize) -> String {
+    (0..moves)
+        .map(|_| {
+            let direction = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+            format!("{} {}\n", direction, rng.random_range(1..=20))
+        })
+        .collect()
+}
+
+// At least the 240 cycles the screen needs, with the sprite kept on or next to the screen.
+fn program(rng: &mut impl Rng, cycles: usize) -> String {
+    let (mut x, mut cycle) = (1, 0);
+    let mut out = String::new();
+    while cycle < cycles.max(240) {
+        if rng.random_bool(0.3) {
+            out.push_str("noop\n");
+            cycle += 1;
+        } else {
+            let to = rng.random_range(-1..=40);
+            if to != x {
+                writeln!(out, "addx {}", to - x).unwrap();
+                (x, cycle) = (to, cycle + 2);
+            }
+        }
+    }
+    out
+}
+
+// One monkey multiplies and the others add, and no one squares: part 1 does not reduce the worry
+// levels modulo the tests, and they must still fit in 64 bits after 20 rounds.
+fn monkeys(rng: &mut impl Rng, items: usize) -> String {
+    let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    divisors.shuffle(rng);
+    let count = rng.random_range(2..=8).min(items.max(2));
+    let mut held = vec![vec![]; count];
+    for i in 0..items.max(count) {
+        let monkey = match i < count {
+            true => i,
+            false => rng.random_range(0..count),
+        };
+        held[monkey].push(rng.random_range(50..=99).to_string());
+    }
+    let multiplier = rng.random_range(0..count);
+
+    let mut blocks = vec![];
+    for (i, items) in held.iter().enumerate() {
+        let operation = match i == multiplier {
+            true => format!("old * {}", rng.random_range(2..=19)),
+            false => format!("old + {}", rng.random_range(1..=8)),
+        };
+        let mut others: Vec<usize> = (0..count).filter(|m| *m != i).collect();
+        others.shuffle(rng);
+        let (if_true, if_false) = (others[0], others[others.len() - 1]);
+        blocks.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            i,
+            items.join(", "),
+            operation,
+            divisors[i],
+            if_true,
+            if_false
+        ));
+    }
+    blocks.join("\n")
+}
+
+// Random heights, with a path from S to E carved through them: it crosses every column, wanders
+// up and down, and climbs steadily from a to z.
+fn heightmap(rng: &mut impl Rng, width: usize) -> String {
+    let (width, height) = (width.max(26), (width / 2).max(5));
+    let mut grid: Vec<Vec<char>> = (0..height)
+        .map(|_| (0..width).map(|_| rng.random_range('a'..='z')).collect())
+        .collect();
+
+    let mut path = vec![];
+    let mut y = rng.random_range(0..height);
+    for x in 0..width {
+        let to = rng.random_range(y.saturating_sub(3)..=(y + 3).min(height - 1));
+        path.push((x, y));
+        while y != to {
+            y = if to > y { y + 1 } else { y - 1 };
+            path.push((x, y));
+        }
+    }
+    let steps = path.len() - 1;
+    for (i, (x, y)) in path.iter().enumerate() {
+        grid[*y][*x] = (b'a' + (i * 25 / steps) as u8) as char;
+    }
+    let ((sx, sy), (ex, ey)) = (path[0], path[steps]);
+    grid[sy][sx] = 'S';
+    grid[ey][ex] = 'E';
+
+    grid.into_iter()
+        .map(|row| String::from_iter(row) + "\n")
+        .collect()
+}
+
+fn packet_pairs(rng: &mut impl Rng, pairs: usize) -> String {
+    (0..pairs)
+        .map(|_| format!("{}\n{}\n", packet(rng, 4), packet(rng, 4)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn packet(rng: &mut impl Rng, depth: u32) -> String {
+    let values: Vec<String> = (0..rng.random_range(0..=5))
+        .map(|_| match depth == 0 || rng.random_bool(0.6) {
+            true => rng.random_range(0..=10).to_string(),
+            false => packet(rng, depth - 1),
+        })
+        .collect();
+    format!("[{}]", values.join(","))
+}
+
+// Paths of straight lines around the sand source, going deeper as there are more of them, and a
+// ledge right under the source so that part 1 has something to fill.
+fn rock_paths(rng: &mut impl Rng, paths: usize) -> String {
+    let depth = paths as i64 + 10;
+    let ledge = format!("490,{0} -> 510,{0}\n", rng.random_range(2..=depth));
+    let others: String = (1..paths)
+        .map(|_| {
+            let (mut x, mut y) = (rng.random_range(460..=540), rng.random_range(2..=depth));
+            let mut points = vec![format!("{},{}", x, y)];
+            for i in 0..rng.random_range(1..=4) {
+                let step = rng.random_range(1..=8) * [-1, 1].choose(rng).unwrap();
+                if i % 2 == 0 {
+                    x += step;
+                } else {
+                    y = (y + step).max(2);
+                }
+                points.push(format!("{},{}", x, y));
+            }
+            points.join(" -> ") + "\n"
+        })
+        .collect();
+    ledge + &others
+}
+
+// Sensors covering the whole search area, `0..=bound` on both axes, but for one position, where
+// the distress beacon is.
+//
+// Diamonds of radius r centred on every other point of a lattice of spacing r tile the plane;
+// those around the gap are shrunk to leave it out, and eight larger sensors just missing it, in
+// every direction, cover what the shrinking left uncovered.
+fn sensor_reports(rng: &mut impl Rng, sensors: usize, bound: i64) -> String {
+    let gap = (rng.random_range(0..=bound), rng.random_range(0..=bound));
+    let r = ((bound as f64 / (2.0 * sensors as f64).sqrt()) as i64).max(1);
+    let (ox, oy) = (rng.random_range(0..r), rng.random_range(0..r));
+    let distance = |(x, y): (i64, i64)| (x - gap.0).abs() + (y - gap.1).abs();
+
+    let mut centres = vec![];
+    let steps = (bound + 2 * r) / r + 1;
+    for a in -1..=steps {
+        for b in (-1..=steps).filter(|b| (a - b) % 2 == 0) {
+            let radius = r + rng.random_range(0..=r / 4);
+            centres.push(((ox + a * r, oy + b * r), radius));
+        }
+    }
+    let k = 3 * r;
+    for (dx, dy) in [
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+    ] {
+        centres.push(((gap.0 + dx * k, gap.1 + dy * k), i64::MAX));
+    }
+    centres.shuffle(rng);
+
+    let mut out = String::new();
+    for (sensor, radius) in centres {
+        let radius = radius.min(distance(sensor) - 1);
+        if radius < 1 {
+            continue;
+        }
+        let dx = rng.random_range(-radius..=radius);
+        let dy = (radius - dx.abs()) * [-1, 1].choose(rng).unwrap();
+        writeln!(
+            out,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.0,
+            sensor.1,
+            sensor.0 + dx,
+            sensor.1 + dy
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn jet_pattern(rng: &mut impl Rng, len: usize) -> String {
+    let jets: String = (0..len)
+        .map(|_| if rng.random_bool(0.5) { '<' } else { '>' })
+        .collect();
+    jets + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Answers;
+    use crate::days::DAYS;
+    use aoc_common::solution::Part;
+    use toml::Table;
+
+    #[test]
+    fn test_reproducible() -> Result<()> {
+        let config = Table::new();
+        assert_eq!(generate(13, 10, 7, &config)?, generate(13, 10, 7, &config)?);
+        assert_ne!(generate(13, 10, 7, &config)?, generate(13, 10, 8, &config)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solvable() -> Result<()> {
+        let solved = Answers::load(Answers::default_path())?;
+        for day in DAYS {
+            let parts: Vec<Part> = Part::ALL
+                .into_iter()
+                .filter(|p| solved.get(day.number, *p).is_some())
+                .collect();
+            // A new day has no generator until its answers are recorded.
+            if parts.is_empty() {
+                continue;
+            }
+            // Day 15 takes minutes on the default search area.
+            let config = match day.number {
+                15 => "row = 500\nbound = 1000".parse::<Table>()?,
+                _ => Table::new(),
+            };
+            for seed in 0..3 {
+                let input = generate(day.number, 30, seed, &config)?;
+                for (part, answer, _) in (day.solve)(&input, &config, &parts)?.parts {
+                    if let Err(e) = answer {
+                        panic!("day {} part {}, seed {}: {:#}", day.number, part, seed, e);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+pub mod answers;
+pub mod days;
+pub mod generate;
+pub mod serve;
+pub mod visual;
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
@@ -0,0 +1,375 @@
+mod explore;
+mod new;
+mod table;
+mod timings;
+mod watch;
+
+use anyhow::{anyhow, bail, Result};
+use aoc::answers::{same_answer, Answers};
+use aoc::days;
+use aoc::generate::generate;
+use aoc::serve::Server;
+use aoc::visual;
+use aoc_common::config;
+use aoc_common::config::Config;
+use aoc_common::input::read_string;
+use aoc_common::output::{self, Format, Record};
+use aoc_common::solution::Part;
+use clap::{Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use timings::Timing;
+use toml::Table;
+use tracing::info_span;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Most detailed log events to print to stderr: off, error, warn, info, debug or trace
+    #[arg(long, global = true, default_value_t = LevelFilter::WARN)]
+    log_level: LevelFilter,
+    /// Print the log as one JSON object per line
+    #[arg(long, global = true)]
+    log_json: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for one day or for all of them, in parallel
+    Run {
+        /// Day to run
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u32>,
+        /// Run only this part
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Read the puzzle input from this file, or from stdin if `-`, instead of the day's input.txt
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Run every day
+        #[arg(long)]
+        all: bool,
+        /// Run against the day's example.txt and check the answers in examples.toml
+        #[arg(long, conflicts_with = "input")]
+        example: bool,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        format: OutputFormat,
+        /// Read the puzzle parameters from this file instead of aoc.toml
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Override a puzzle parameter of the day, e.g. `--set row=10`
+        #[arg(long = "set", value_name = "KEY=VALUE", value_parser = config::parse_override, conflicts_with = "all")]
+        overrides: Vec<(String, toml::Value)>,
+        /// Print how long each day's parsing and parts took to stderr
+        #[arg(long)]
+        timings: bool,
+        /// Watch the simulation of a part instead of printing the answers: in the terminal, or
+        /// saved to PATH as a PPM or PNG image of its last frame or as an animated GIF
+        #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "-", conflicts_with_all = ["all", "timings"])]
+        visualize: Option<PathBuf>,
+        /// Frames per second of the simulation being watched
+        #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..), requires = "visualize")]
+        fps: u32,
+    },
+    /// Print a random puzzle input for a day, the same for the same size and seed
+    Gen {
+        /// Day to generate an input for
+        day: u32,
+        /// Roughly how many items the input holds: lines, moves, grid columns...
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Seed of the random generator
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Override a puzzle parameter the input must suit, e.g. `--set bound=20`
+        #[arg(long = "set", value_name = "KEY=VALUE", value_parser = config::parse_override)]
+        overrides: Vec<(String, toml::Value)>,
+    },
+    /// Answer puzzle inputs posted over HTTP on localhost, in JSON
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8022)]
+        port: u16,
+        /// Read the puzzle parameters from this file instead of aoc.toml
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Rebuild and rerun a day whenever its source or input changes
+    Watch {
+        /// Day to watch
+        day: u32,
+        /// Read the puzzle input from this file instead of the day's input.txt, which has no
+        /// recorded answers to compare with
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Run against the day's example.txt and compare with the answers in examples.toml
+        #[arg(long, conflicts_with = "input")]
+        example: bool,
+        /// Build with optimizations, for the slower days
+        #[arg(long)]
+        release: bool,
+    },
+    /// Pick a day's simulation and step through it in the terminal
+    Explore {
+        /// Run the simulations on the days' example.txt instead of their input.txt
+        #[arg(long)]
+        example: bool,
+        /// Read the puzzle parameters from this file instead of aoc.toml
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Create a new day crate from the template and register it in the runner
+    New {
+        /// Day to create
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// Aligned columns for reading in a terminal
+    Table,
+    /// One JSON record per line
+    Json,
+    /// Comma-separated records with a header line
+    Csv,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    init_log(cli.log_level, cli.log_json);
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            all: _,
+            example,
+            format,
+            config,
+            overrides,
+            timings,
+            visualize,
+            fps,
+        } => {
+            let parts = match part {
+                Some(p) => vec![Part::try_from(p)?],
+                None => Part::ALL.to_vec(),
+            };
+            let days = match day {
+                Some(n) => vec![days::find(n).ok_or(anyhow!("day {} is not solved", n))?],
+                None => days::DAYS.iter().collect(),
+            };
+            // Other days' unsolved parts are skipped, but asking for one alone is a mistake.
+            if let ([day], [part]) = (days.as_slice(), parts.as_slice()) {
+                if !day.parts.contains(part) {
+                    bail!("part {} of day {} is not solved yet", part, day.number);
+                }
+            }
+            let input = match (input, example) {
+                (Some(path), _) => Input::Path(path),
+                (None, true) => Input::Example(Answers::load(Answers::examples_path())?),
+                (None, false) => Input::Default,
+            };
+            let config = Config::load(config.unwrap_or_else(Config::default_path))?;
+            let overrides = overrides.into_iter().collect();
+            if let Some(output) = visualize {
+                return play(days[0], parts[0], &input, &config, &overrides, &output, fps);
+            }
+            run(&days, &parts, &input, &config, &overrides, format, timings)
+        }
+        Command::Gen {
+            day,
+            size,
+            seed,
+            overrides,
+        } => {
+            let mut config = Config::load(Config::default_path())?.get(day);
+            config.extend(overrides);
+            print!("{}", generate(day, size, seed, &config)?);
+            Ok(())
+        }
+        Command::Serve { port, config } => {
+            let config = Config::load(config.unwrap_or_else(Config::default_path))?;
+            let server = Server::bind(port, config)?;
+            println!("listening on http://{}", server.addr());
+            server.run();
+            Ok(())
+        }
+        Command::Watch {
+            day,
+            input,
+            example,
+            release,
+        } => {
+            let day = days::find(day).ok_or(anyhow!("day {} is not solved", day))?;
+            let source = match (input, example) {
+                (_, true) => watch::Source::Example,
+                (Some(path), false) => watch::Source::File(path),
+                (None, false) => watch::Source::Default,
+            };
+            watch::watch(day, &source, release)
+        }
+        Command::Explore { example, config } => {
+            let input = if example {
+                Input::Example(Answers::load(Answers::examples_path())?)
+            } else {
+                Input::Default
+            };
+            let config = Config::load(config.unwrap_or_else(Config::default_path))?;
+            explore::run(&|day| {
+                let (path, day_config) = day_input(day, &input, &config, &Table::new());
+                Ok((read_string(&path)?, day_config))
+            })
+        }
+        Command::New { day } => {
+            new::scaffold(&aoc::workspace_root(), day)?;
+            println!("created day-{:02}", day);
+            Ok(())
+        }
+    }
+}
+
+// Logs to stderr, with the time spent in each span when it closes: at `info`, every day's
+// parsing and parts.
+fn init_log(level: LevelFilter, json: bool) {
+    let log = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_writer(std::io::stderr);
+    if json {
+        log.json().init();
+    } else {
+        log.init();
+    }
+}
+
+enum Input {
+    Default,
+    Path(PathBuf),
+    /// The day's example, whose answers are checked against the expected ones.
+    Example(Answers),
+}
+
+fn run(
+    days: &[&days::Day],
+    parts: &[Part],
+    input: &Input,
+    config: &Config,
+    overrides: &Table,
+    format: OutputFormat,
+    timings: bool,
+) -> Result<()> {
+    let start = Instant::now();
+    // Collected in day order, so the output does not depend on which day finishes first.
+    let solved: Vec<_> = days
+        .par_iter()
+        .map(|day| {
+            let _span = info_span!("day", day = day.number).entered();
+            let (path, day_config) = day_input(day, input, config, overrides);
+            let answers = read_string(&path).and_then(|i| (day.solve)(&i, &day_config, parts));
+            (path, answers)
+        })
+        .collect();
+    let wall_time = start.elapsed();
+
+    let mut rows = vec![];
+    let mut times = vec![];
+    for (day, (path, answers)) in days.iter().zip(solved) {
+        let answers = output::diagnose(&path, answers);
+        if let Ok(answers) = &answers {
+            times.push(Timing::new(day.number, answers));
+        }
+        let mut records = output::records(day.number, &path, answers);
+        if let Input::Example(expected) = input {
+            records.iter_mut().for_each(|r| check(r, expected));
+        }
+        rows.extend(records);
+    }
+
+    match format {
+        OutputFormat::Table => table::print(&rows),
+        OutputFormat::Json => output::print(Format::Json, &rows),
+        OutputFormat::Csv => output::print(Format::Csv, &rows),
+    }
+    if timings {
+        timings::print(&times, wall_time);
+    }
+
+    let failures = rows.iter().filter(|r| r.error.is_some()).count();
+    if failures > 0 {
+        bail!("{} of {} runs failed", failures, rows.len());
+    }
+    Ok(())
+}
+
+// The day's input file and its puzzle parameters.
+fn day_input(
+    day: &days::Day,
+    input: &Input,
+    config: &Config,
+    overrides: &Table,
+) -> (PathBuf, Table) {
+    let path = match input {
+        Input::Default => day.default_input(),
+        Input::Path(path) => path.clone(),
+        Input::Example(_) => day.example_input(),
+    };
+    let mut day_config = config.get(day.number);
+    if let Input::Example(expected) = input {
+        day_config.extend(expected.config(day.number).cloned().unwrap_or_default());
+    }
+    day_config.extend(overrides.clone());
+    (path, day_config)
+}
+
+// Plays the simulation of a part of a day into the sink for `output`.
+fn play(
+    day: &days::Day,
+    part: Part,
+    input: &Input,
+    config: &Config,
+    overrides: &Table,
+    output: &Path,
+    fps: u32,
+) -> Result<()> {
+    let visualize = day
+        .visualize
+        .ok_or(anyhow!("day {} has no simulation to watch", day.number))?;
+    let (path, day_config) = day_input(day, input, config, overrides);
+    let mut sink = visual::sink(output, fps)?;
+    let input = read_string(&path)?;
+    output::diagnose(
+        &path,
+        visualize(&input, &day_config, part, &mut |frame, caption| {
+            sink.frame(frame, caption)
+        }),
+    )?;
+    sink.finish()
+}
+
+// Turns an answer that differs from the expected one into an error.
+fn check(record: &mut Record, expected: &Answers) {
+    let (Some(part), Some(actual)) = (record.part, &record.answer) else {
+        return;
+    };
+    let Some(expected) = expected.get(record.day, Part::try_from(part).unwrap()) else {
+        return;
+    };
+    if !same_answer(expected, actual) {
+        record.error = Some(format!(
+            "expected {:?}, got {:?}",
+            expected.trim_matches('\n'),
+            actual
+        ));
+    }
+}
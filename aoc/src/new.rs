@@ -0,0 +1,253 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Creates `day-NN` from `template/` and registers it in the workspace, in the runner, in the
+/// benchmarks and in `answers.toml`, with a section for the answers to come.
+pub fn scaffold(root: &Path, day: u32) -> Result<()> {
+    let dir_name = format!("day-{:02}", day);
+    let dir = root.join(&dir_name);
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    copy_template(&root.join("template"), &dir, day)
+        .with_context(|| format!("cannot create {}", dir.display()))?;
+
+    edit(&root.join("Cargo.toml"), |s| add_member(s, day))?;
+    edit(&root.join("aoc").join("Cargo.toml"), |s| {
+        add_dependency(s, day)
+    })?;
+    edit(&root.join("aoc").join("src").join("days.rs"), |s| {
+        add_day(s, day)
+    })?;
+    edit(&root.join("aoc-bench").join("Cargo.toml"), |s| {
+        add_dependency(s, day)
+    })?;
+    edit(
+        &root.join("aoc-bench").join("benches").join("days.rs"),
+        |s| add_bench(s, day),
+    )?;
+    edit(&root.join("answers.toml"), |s| Ok(add_answers(s, day)))?;
+
+    Ok(())
+}
+
+fn copy_template(from: &Path, to: &Path, day: u32) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if name == "target" || name == "Cargo.lock" || name == "input.txt" {
+            continue;
+        }
+
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            copy_template(&path, &to.join(&name), day)?;
+        } else {
+            let content = fs::read_to_string(&path)?
+                .replace("<DayNn>(0)", &format!("<DayNn>({})", day))
+                .replace("day_nn", &format!("day_{:02}", day))
+                .replace("DayNn", &format!("Day{:02}", day));
+            fs::write(to.join(&name), content)?;
+        }
+    }
+    Ok(())
+}
+
+fn edit<F: Fn(&str) -> Result<String>>(path: &Path, f: F) -> Result<()> {
+    let content = fs::read_to_string(path)?;
+    let edited = f(&content).with_context(|| format!("cannot register in {}", path.display()))?;
+    fs::write(path, edited)?;
+    Ok(())
+}
+
+// Inserts `entry` before the first line naming a later day, or after the last day.
+fn insert_sorted(s: &str, entry: &str, prefix: &str, day: u32) -> Result<String> {
+    let names_day = |l: &&str| l.trim_start().starts_with(prefix);
+    let key = entry.lines().position(|l| names_day(&l)).unwrap_or(0);
+    let len = entry.lines().count();
+
+    let mut lines: Vec<&str> = s.lines().collect();
+    let pos = match lines.iter().position(|l| is_later_day(l, prefix, day)) {
+        Some(i) => i - key,
+        None => {
+            lines
+                .iter()
+                .rposition(names_day)
+                .ok_or(anyhow!("no '{}' entries", prefix))?
+                - key
+                + len
+        }
+    };
+    lines.insert(pos, entry);
+
+    Ok(format!("{}\n", lines.join("\n")))
+}
+
+fn is_later_day(line: &str, prefix: &str, day: u32) -> bool {
+    line.trim_start()
+        .strip_prefix(prefix)
+        .map(|rest| {
+            rest.chars()
+                .take_while(char::is_ascii_digit)
+                .collect::<String>()
+        })
+        .and_then(|n| n.parse::<u32>().ok())
+        .map(|n| n > day)
+        .unwrap_or(false)
+}
+
+fn add_member(s: &str, day: u32) -> Result<String> {
+    insert_sorted(s, &format!("    \"day-{:02}\",", day), "\"day-", day)
+}
+
+fn add_dependency(s: &str, day: u32) -> Result<String> {
+    let entry = format!("day_{:02} = {{ path = \"../day-{:02}\" }}", day, day);
+    insert_sorted(s, &entry, "day_", day)
+}
+
+fn add_day(s: &str, day: u32) -> Result<String> {
+    let entry = format!(
+        "    Day {{\n        number: {0},\n        solve: solve::<day_{0:02}::Day{0:02}>,\n        parts: day_{0:02}::Day{0:02}::PARTS,\n        visualize: None,\n    }},",
+        day
+    );
+    insert_sorted(s, &entry, "number: ", day)
+}
+
+fn add_bench(s: &str, day: u32) -> Result<String> {
+    let entry = format!(
+        "    bench_day::<day_{0:02}::Day{0:02}>(c, &answers, &configs, {0});",
+        day
+    );
+    insert_sorted(s, &entry, "bench_day::<day_", day)
+}
+
+// An empty section for the day, in order, which is enough for the day to count as recorded.
+fn add_answers(s: &str, day: u32) -> String {
+    let section = format!("[{}]\n", day);
+    let mut at = 0;
+    for line in s.split_inclusive('\n') {
+        if is_later_day(line, "[", day) {
+            return format!("{}{}\n{}", &s[..at], section, &s[at..]);
+        }
+        at += line.len();
+    }
+    format!("{}\n\n{}", s.trim_end(), section)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member() -> Result<()> {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-01\",\n    \"day-17\",\n    \"template\",\n]\n";
+        assert_eq!(
+            add_member(manifest, 5)?,
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-01\",\n    \"day-05\",\n    \"day-17\",\n    \"template\",\n]\n"
+        );
+        assert_eq!(
+            add_member(manifest, 18)?,
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-01\",\n    \"day-17\",\n    \"day-18\",\n    \"template\",\n]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_dependency() -> Result<()> {
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\nanyhow = \"1\"\nday_01 = { path = \"../day-01\" }\nday_17 = { path = \"../day-17\" }\nserde = \"1\"\n";
+        assert_eq!(
+            add_dependency(manifest, 16)?,
+            "[package]\nname = \"aoc\"\n\n[dependencies]\nanyhow = \"1\"\nday_01 = { path = \"../day-01\" }\nday_16 = { path = \"../day-16\" }\nday_17 = { path = \"../day-17\" }\nserde = \"1\"\n"
+        );
+        assert_eq!(
+            add_dependency(manifest, 18)?,
+            "[package]\nname = \"aoc\"\n\n[dependencies]\nanyhow = \"1\"\nday_01 = { path = \"../day-01\" }\nday_17 = { path = \"../day-17\" }\nday_18 = { path = \"../day-18\" }\nserde = \"1\"\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_bench() -> Result<()> {
+        let bench = "fn days(c: &mut Criterion) {\n    bench_day::<day_01::Day01>(c, &answers, &configs, 1);\n    bench_day::<day_17::Day17>(c, &answers, &configs, 17);\n}\n";
+        assert_eq!(
+            add_bench(bench, 16)?,
+            "fn days(c: &mut Criterion) {\n    bench_day::<day_01::Day01>(c, &answers, &configs, 1);\n    bench_day::<day_16::Day16>(c, &answers, &configs, 16);\n    bench_day::<day_17::Day17>(c, &answers, &configs, 17);\n}\n"
+        );
+        assert_eq!(
+            add_bench(bench, 18)?,
+            "fn days(c: &mut Criterion) {\n    bench_day::<day_01::Day01>(c, &answers, &configs, 1);\n    bench_day::<day_17::Day17>(c, &answers, &configs, 17);\n    bench_day::<day_18::Day18>(c, &answers, &configs, 18);\n}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_answers() {
+        let answers = "# Answers.\n\n[1]\npart1 = \"1\"\n\n[17]\npart1 = \"17\"\n";
+        assert_eq!(
+            add_answers(answers, 16),
+            "# Answers.\n\n[1]\npart1 = \"1\"\n\n[16]\n\n[17]\npart1 = \"17\"\n"
+        );
+        assert_eq!(
+            add_answers(answers, 18),
+            "# Answers.\n\n[1]\npart1 = \"1\"\n\n[17]\npart1 = \"17\"\n\n[18]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_day() -> Result<()> {
+        let days = r#"use foo;
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solve: solve::<day_01::Day01>,
+        parts: day_01::Day01::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 17,
+        solve: solve::<day_17::Day17>,
+        parts: day_17::Day17::PARTS,
+        visualize: None,
+    },
+];
+
+pub fn find() {}
+"#;
+        assert_eq!(
+            add_day(days, 16)?,
+            r#"use foo;
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solve: solve::<day_01::Day01>,
+        parts: day_01::Day01::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 16,
+        solve: solve::<day_16::Day16>,
+        parts: day_16::Day16::PARTS,
+        visualize: None,
+    },
+    Day {
+        number: 17,
+        solve: solve::<day_17::Day17>,
+        parts: day_17::Day17::PARTS,
+        visualize: None,
+    },
+];
+
+pub fn find() {}
+"#
+        );
+        assert!(add_day(days, 18)?.contains(
+            "        visualize: None,\n    },\n    Day {\n        number: 18,\n        solve: solve::<day_18::Day18>,\n        parts: day_18::Day18::PARTS,\n        visualize: None,\n    },\n];\n"
+        ));
+        Ok(())
+    }
+}
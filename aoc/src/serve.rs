@@ -0,0 +1,293 @@
+use crate::days::{self, Day};
+use anyhow::{anyhow, Context, Result};
+use aoc_common::config::{self, Config};
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answers, Part};
+use serde::Serialize;
+use std::io::Read;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response};
+use toml::Table;
+use tracing::{error, info, info_span};
+
+// Far longer than any puzzle input.
+const MAX_BODY: usize = 1 << 20;
+
+/// Solves the puzzle inputs posted to it over HTTP, answering in JSON. It only listens on
+/// localhost, and takes inputs of up to a mebibyte.
+///
+/// - `GET /days` lists the days that are solved.
+/// - `POST /days/{day}` solves both parts of the input in the body, or only the first if the
+///   second is not solved yet, and `POST /days/{day}/parts/{part}` one of them. Puzzle parameters can be overridden in the
+///   query string, as with `--set`: `?row=10&bound=20`.
+pub struct Server {
+    server: tiny_http::Server,
+    config: Config,
+}
+
+impl Server {
+    /// Listens on `port`, or on any free port if it is 0, solving with the parameters in `config`.
+    pub fn bind(port: u16, config: Config) -> Result<Server> {
+        let server = tiny_http::Server::http((Ipv4Addr::LOCALHOST, port))
+            .map_err(|e| anyhow!("cannot listen on port {}: {}", port, e))?;
+        Ok(Server { server, config })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.server
+            .server_addr()
+            .to_ip()
+            .expect("the server listens on an IP address")
+    }
+
+    /// Answers requests on a thread per CPU until the process ends. Further requests wait for a
+    /// thread to be free.
+    pub fn run(self) {
+        let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+        std::thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    while let Ok(request) = self.server.recv() {
+                        self.respond(request);
+                    }
+                });
+            }
+        });
+    }
+
+    fn respond(&self, mut request: Request) {
+        let _span =
+            info_span!("request", method = %request.method(), url = request.url()).entered();
+        let reply = match read_body(&mut request) {
+            Ok(body) => self.handle(request.method(), request.url(), &body),
+            Err(reply) => reply,
+        };
+        info!(status = reply.status, "answered");
+        let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let response = Response::from_string(reply.body)
+            .with_status_code(reply.status)
+            .with_header(header);
+        // The client may have gone away, and there is no one else to tell.
+        let _ = request.respond(response);
+    }
+
+    fn handle(&self, method: &Method, url: &str, body: &str) -> Reply {
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match (method, segments.as_slice()) {
+            (Method::Get, ["days"]) => Reply::ok(
+                200,
+                &days::DAYS.iter().map(|d| d.number).collect::<Vec<_>>(),
+            ),
+            (Method::Post, ["days", day]) => self.solve(day, &Part::ALL, query, body),
+            (Method::Post, ["days", day, "parts", part]) => match parse_part(part) {
+                Ok(part) => self.solve(day, &[part], query, body),
+                Err(e) => Reply::error(404, e),
+            },
+            (_, ["days"] | ["days", _] | ["days", _, "parts", _]) => {
+                Reply::error(405, anyhow!("{} is not allowed on {}", method, path))
+            }
+            _ => Reply::error(404, anyhow!("no such endpoint: {}", path)),
+        }
+    }
+
+    fn solve(&self, day: &str, parts: &[Part], query: &str, input: &str) -> Reply {
+        let Some(day) = day.parse().ok().and_then(days::find) else {
+            return Reply::error(404, anyhow!("day {} is not solved", day));
+        };
+        // Asking for both parts skips one that is not solved yet, but asking for it alone fails.
+        if let [part] = parts {
+            if !day.parts.contains(part) {
+                let e = anyhow!("part {} of day {} is not solved yet", part, day.number);
+                return Reply::error(404, e);
+            }
+        }
+        let config = match self.day_config(day, query) {
+            Ok(config) => config,
+            Err(e) => return Reply::error(400, e),
+        };
+        let _span = info_span!("day", day = day.number).entered();
+        // Solutions reject the inputs they cannot solve, so a panic is a bug in one. It should
+        // still not take the server down with it.
+        match panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(input, &config, parts))) {
+            Ok(Ok(answers)) => Solved::new(day.number, answers).reply(),
+            Ok(Err(e)) => Reply::error(400, e),
+            Err(panic) => {
+                let message = panic
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("no message");
+                error!(
+                    message,
+                    "BUG: the solution panicked instead of rejecting the input"
+                );
+                Reply::error(500, anyhow!("day {} panicked", day.number))
+            }
+        }
+    }
+
+    // The day's parameters, with the overrides in the query string.
+    fn day_config(&self, day: &Day, query: &str) -> Result<Table> {
+        let mut table = self.config.get(day.number);
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = config::parse_override(pair)?;
+            table.insert(key, value);
+        }
+        Ok(table)
+    }
+}
+
+// The body, unless it is too long or not UTF-8, in which case the reply says so.
+fn read_body(request: &mut Request) -> Result<String, Reply> {
+    let too_long = || Reply::error(413, anyhow!("the input is longer than {} bytes", MAX_BODY));
+    if request.body_length().is_some_and(|n| n > MAX_BODY) {
+        return Err(too_long());
+    }
+    // The length may not be given up front, so read one byte more than allowed to tell.
+    let mut body = String::new();
+    request
+        .as_reader()
+        .take(MAX_BODY as u64 + 1)
+        .read_to_string(&mut body)
+        .context("cannot read the input, which must be UTF-8")
+        .map_err(|e| Reply::error(400, e))?;
+    if body.len() > MAX_BODY {
+        return Err(too_long());
+    }
+    Ok(body)
+}
+
+fn parse_part(s: &str) -> Result<Part> {
+    let n: u32 = s.parse().map_err(|_| anyhow!("invalid part: '{}'", s))?;
+    Part::try_from(n)
+}
+
+struct Reply {
+    status: u16,
+    body: String,
+}
+
+impl Reply {
+    fn ok<T: Serialize>(status: u16, value: &T) -> Reply {
+        Reply {
+            status,
+            body: serde_json::to_string(value).unwrap(),
+        }
+    }
+
+    fn error(status: u16, error: anyhow::Error) -> Reply {
+        Reply::ok(
+            status,
+            &ErrorReply {
+                error: Error::new(&error),
+            },
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorReply {
+    error: Error,
+}
+
+/// What went wrong, and where in the input for a parse error.
+#[derive(Debug, Serialize, PartialEq)]
+struct Error {
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diagnostic: Option<String>,
+}
+
+impl Error {
+    fn new(error: &anyhow::Error) -> Error {
+        match error.downcast_ref::<ParseError>() {
+            Some(e) => Error {
+                message: e.message().to_string(),
+                line: Some(e.line()),
+                column: Some(e.column()),
+                diagnostic: Some(e.diagnostic()),
+            },
+            None => Error {
+                message: format!("{:#}", error),
+                line: None,
+                column: None,
+                diagnostic: None,
+            },
+        }
+    }
+}
+
+/// The answers to a day's parts, with the time each phase took.
+#[derive(Serialize)]
+struct Solved {
+    day: u32,
+    parse_ms: f64,
+    parts: Vec<PartReply>,
+}
+
+#[derive(Serialize)]
+struct PartReply {
+    part: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<Error>,
+    elapsed_ms: f64,
+}
+
+impl Solved {
+    fn new(day: u32, answers: Answers) -> Solved {
+        let parts = answers
+            .parts
+            .into_iter()
+            .map(|(part, answer, elapsed)| {
+                let (answer, error) = match answer {
+                    Ok(a) => (Some(a.trim_matches('\n').to_string()), None),
+                    Err(e) => (None, Some(Error::new(&e))),
+                };
+                PartReply {
+                    part: part.number(),
+                    answer,
+                    error,
+                    elapsed_ms: ms(elapsed),
+                }
+            })
+            .collect();
+        Solved {
+            day,
+            parse_ms: ms(answers.parse_time),
+            parts,
+        }
+    }
+
+    // A part that fails makes the whole request fail, though the other parts are still answered.
+    fn reply(&self) -> Reply {
+        let failed = self.parts.iter().any(|p| p.error.is_some());
+        Reply::ok(if failed { 422 } else { 200 }, self)
+    }
+}
+
+fn ms(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error() {
+        let e = ParseError::new("1\nx\n", "x", "invalid number").into();
+        let error = Error::new(&e);
+        assert_eq!(error.message, "invalid number");
+        assert_eq!((error.line, error.column), (Some(2), Some(1)));
+        assert_eq!(Error::new(&anyhow!("no")).line, None);
+    }
+}
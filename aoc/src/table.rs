@@ -0,0 +1,54 @@
+use aoc_common::output::Record;
+
+pub fn print(rows: &[Record]) {
+    print!("{}", render(rows));
+}
+
+fn render(rows: &[Record]) -> String {
+    let mut out = String::from("Day  Part  Answer\n");
+    for row in rows {
+        let part = row.part.map(|p| p.to_string()).unwrap_or("-".to_string());
+        let answer = match (&row.answer, &row.error) {
+            (_, Some(e)) => format!("error: {}", e),
+            (Some(a), None) => a.clone(),
+            (None, None) => String::new(),
+        };
+        for (i, line) in answer.lines().enumerate() {
+            if i == 0 {
+                out.push_str(&format!("{:>3}  {:>4}  {}\n", row.day, part, line));
+            } else {
+                out.push_str(&format!("{:>3}  {:>4}  {}\n", "", "", line));
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use aoc_common::solution::Part;
+    use std::path::Path;
+    use std::time::Duration;
+
+    #[test]
+    fn test_render() {
+        let row = |day, part, answer| Record::new(day, part, answer, Duration::ZERO, Path::new(""));
+        let rows = vec![
+            row(1, Some(Part::One), Ok("42".to_string())),
+            row(10, Some(Part::Two), Ok("\n#.\n.#\n".to_string())),
+            row(12, None, Err(anyhow!("no path"))),
+        ];
+
+        assert_eq!(
+            render(&rows),
+            r#"Day  Part  Answer
+  1     1  42
+ 10     2  #.
+           .#
+ 12     -  error: no path
+"#
+        );
+    }
+}
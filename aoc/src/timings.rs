@@ -0,0 +1,132 @@
+use aoc_common::solution::{Answers, Part};
+use std::time::Duration;
+
+// How many of the slowest days the summary names.
+const SLOWEST: usize = 3;
+
+/// How long each phase of a day took.
+pub struct Timing {
+    pub day: u32,
+    pub parse: Duration,
+    /// The time of each part, or `None` if it was not run.
+    pub parts: [Option<Duration>; 2],
+}
+
+impl Timing {
+    pub fn new(day: u32, answers: &Answers) -> Timing {
+        let mut parts = [None; 2];
+        for (part, _, elapsed) in &answers.parts {
+            parts[part.number() as usize - 1] = Some(*elapsed);
+        }
+        Timing {
+            day,
+            parse: answers.parse_time,
+            parts,
+        }
+    }
+
+    fn total(&self) -> Duration {
+        self.parse + self.parts.iter().flatten().sum::<Duration>()
+    }
+}
+
+/// Prints the timings in day order to stderr, then the slowest days and the wall-clock time of the
+/// whole run, which is less than the total when days ran in parallel.
+pub fn print(timings: &[Timing], wall_time: Duration) {
+    eprint!("{}", render(timings, wall_time));
+}
+
+fn render(timings: &[Timing], wall_time: Duration) -> String {
+    let mut out = format!(
+        "{:<5}{:>12}{:>12}{:>12}{:>12}\n",
+        "Day", "Parse ms", "Part 1 ms", "Part 2 ms", "Total ms"
+    );
+    let mut sorted: Vec<&Timing> = timings.iter().collect();
+    sorted.sort_by_key(|t| t.day);
+    for t in &sorted {
+        out.push_str(&row(&t.day.to_string(), Some(t.parse), t.parts, t.total()));
+    }
+
+    let parse = timings.iter().map(|t| t.parse).sum();
+    let parts = Part::ALL.map(|p| {
+        let times = timings
+            .iter()
+            .filter_map(|t| t.parts[p.number() as usize - 1]);
+        times.reduce(|a, b| a + b)
+    });
+    let total = timings.iter().map(|t| t.total()).sum();
+    out.push_str(&row("Total", Some(parse), parts, total));
+
+    // The sort is stable, so days that took as long stay in day order.
+    sorted.sort_by_key(|t| std::cmp::Reverse(t.total()));
+    let slowest: Vec<String> = sorted
+        .iter()
+        .take(SLOWEST)
+        .map(|t| format!("day {} ({} ms)", t.day, ms(t.total())))
+        .collect();
+    if !slowest.is_empty() {
+        out.push_str(&format!("Slowest: {}\n", slowest.join(", ")));
+    }
+    out.push_str(&format!("Ran in {} ms\n", ms(wall_time)));
+    out
+}
+
+fn row(
+    day: &str,
+    parse: Option<Duration>,
+    parts: [Option<Duration>; 2],
+    total: Duration,
+) -> String {
+    let cell = |d: Option<Duration>| d.map(ms).unwrap_or("-".to_string());
+    format!(
+        "{:<5}{:>12}{:>12}{:>12}{:>12}\n",
+        day,
+        cell(parse),
+        cell(parts[0]),
+        cell(parts[1]),
+        ms(total)
+    )
+}
+
+fn ms(d: Duration) -> String {
+    format!("{:.3}", d.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let ms = Duration::from_millis;
+        let timings = vec![
+            Timing {
+                day: 12,
+                parse: ms(1),
+                parts: [Some(ms(20)), Some(ms(30))],
+            },
+            Timing {
+                day: 1,
+                parse: ms(2),
+                parts: [Some(ms(3)), None],
+            },
+            Timing {
+                day: 15,
+                parse: ms(0),
+                parts: [Some(ms(400)), Some(ms(600))],
+            },
+        ];
+
+        assert_eq!(
+            render(&timings, ms(1002)),
+            r#"Day      Parse ms   Part 1 ms   Part 2 ms    Total ms
+1           2.000       3.000           -       5.000
+12          1.000      20.000      30.000      51.000
+15          0.000     400.000     600.000    1000.000
+Total       3.000     423.000     630.000    1056.000
+Slowest: day 15 (1000.000 ms), day 12 (51.000 ms), day 1 (5.000 ms)
+Ran in 1002.000 ms
+"#
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::visual::{Frame, Rgb};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Pixels on each side of the square a glyph becomes in an image.
+const SCALE: usize = 4;
+
+/// Where the frames of a simulation go.
+pub trait Sink {
+    fn frame(&mut self, frame: &Frame, caption: &str) -> Result<()>;
+    /// Called once the simulation is over.
+    fn finish(&mut self) -> Result<()>;
+}
+
+/// The sink for `path`: the terminal for `-`, the last frame as an image for a `.ppm` or `.png`
+/// file, or every frame for a `.gif` file. Animations play `fps` frames per second.
+pub fn sink(path: &Path, fps: u32) -> Result<Box<dyn Sink>> {
+    let delay = Duration::from_secs(1) / fps.max(1);
+    if path == Path::new("-") {
+        return Ok(Box::new(Terminal {
+            delay,
+            started: false,
+        }));
+    }
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    Ok(match extension.to_ascii_lowercase().as_str() {
+        "ppm" => Box::new(Still::new(path, ppm)),
+        "png" => Box::new(Still::new(path, png)),
+        "gif" => Box::new(Gif {
+            path: path.to_path_buf(),
+            // GIF delays are in hundredths of a second, and most viewers slow down shorter ones.
+            delay: (delay.as_millis() / 10).clamp(2, u16::MAX as u128) as u16,
+            encoder: None,
+        }),
+        _ => bail!(
+            "cannot tell the format of {}: expected a .ppm, .png or .gif file, or - for the terminal",
+            path.display()
+        ),
+    })
+}
+
+// Redraws each frame over the previous one in place.
+struct Terminal {
+    delay: Duration,
+    started: bool,
+}
+
+impl Sink for Terminal {
+    fn frame(&mut self, frame: &Frame, caption: &str) -> Result<()> {
+        let mut out = std::io::stdout().lock();
+        if !self.started {
+            // Clears the screen once, so that frames only move the cursor back to the top.
+            write!(out, "\x1b[2J")?;
+            self.started = true;
+        }
+        // The caption is cleared to the end of its line, in case the previous one was longer.
+        writeln!(out, "\x1b[H{}{}\x1b[K", ansi(frame), caption)?;
+        out.flush()?;
+        std::thread::sleep(self.delay);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+// The frame as lines of text, changing the colour only where it differs from the previous glyph.
+fn ansi(frame: &Frame) -> String {
+    let mut out = String::new();
+    for y in 0..frame.height() {
+        let mut colour = None;
+        for glyph in frame.row(y) {
+            if colour != Some(glyph.colour) {
+                let Rgb(r, g, b) = glyph.colour;
+                out.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                colour = Some(glyph.colour);
+            }
+            out.push(glyph.ch);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+// Keeps the latest frame, and saves it without its caption when the simulation is over.
+struct Still {
+    path: PathBuf,
+    encode: fn(&Frame) -> Result<Vec<u8>>,
+    last: Option<Frame>,
+}
+
+impl Still {
+    fn new(path: &Path, encode: fn(&Frame) -> Result<Vec<u8>>) -> Still {
+        Still {
+            path: path.to_path_buf(),
+            encode,
+            last: None,
+        }
+    }
+}
+
+impl Sink for Still {
+    fn frame(&mut self, frame: &Frame, _: &str) -> Result<()> {
+        self.last = Some(frame.clone());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        let frame = self
+            .last
+            .as_ref()
+            .ok_or(anyhow!("the simulation drew nothing"))?;
+        std::fs::write(&self.path, (self.encode)(frame)?)
+            .with_context(|| format!("cannot write {}", self.path.display()))
+    }
+}
+
+// The frame's pixels, one block of `SCALE` by `SCALE` per glyph, row by row.
+fn pixels(frame: &Frame) -> Vec<Rgb> {
+    let mut pixels = Vec::with_capacity(frame.width() * frame.height() * SCALE * SCALE);
+    for y in 0..frame.height() {
+        for _ in 0..SCALE {
+            for glyph in frame.row(y) {
+                pixels.extend([glyph.colour; SCALE]);
+            }
+        }
+    }
+    pixels
+}
+
+fn ppm(frame: &Frame) -> Result<Vec<u8>> {
+    let (width, height) = (frame.width() * SCALE, frame.height() * SCALE);
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    out.extend(pixels(frame).into_iter().flat_map(|Rgb(r, g, b)| [r, g, b]));
+    Ok(out)
+}
+
+fn png(frame: &Frame) -> Result<Vec<u8>> {
+    let (width, height) = (frame.width() * SCALE, frame.height() * SCALE);
+    let mut out = vec![];
+    let mut encoder = png::Encoder::new(&mut out, width.try_into()?, height.try_into()?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let data: Vec<u8> = pixels(frame)
+        .into_iter()
+        .flat_map(|Rgb(r, g, b)| [r, g, b])
+        .collect();
+    encoder.write_header()?.write_image_data(&data)?;
+    Ok(out)
+}
+
+// Appends each frame, without its caption, to an animation, created with the size of the first one.
+struct Gif {
+    path: PathBuf,
+    /// Hundredths of a second between frames.
+    delay: u16,
+    encoder: Option<gif::Encoder<BufWriter<File>>>,
+}
+
+impl Sink for Gif {
+    fn frame(&mut self, frame: &Frame, _: &str) -> Result<()> {
+        let (width, height) = (frame.width() * SCALE, frame.height() * SCALE);
+        let (width, height): (u16, u16) = (width.try_into()?, height.try_into()?);
+        if self.encoder.is_none() {
+            let file = File::create(&self.path)
+                .with_context(|| format!("cannot write {}", self.path.display()))?;
+            let mut encoder = gif::Encoder::new(BufWriter::new(file), width, height, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            self.encoder = Some(encoder);
+        }
+
+        let (indices, palette) = indexed(frame)?;
+        let mut gif_frame = gif::Frame::from_palette_pixels(width, height, indices, palette, None);
+        gif_frame.delay = self.delay;
+        self.encoder.as_mut().unwrap().write_frame(&gif_frame)?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        let encoder = self
+            .encoder
+            .take()
+            .ok_or(anyhow!("the simulation drew nothing"))?;
+        encoder.into_inner()?.flush()?;
+        Ok(())
+    }
+}
+
+// The frame's pixels as indices into a palette of its colours, as GIF frames store them.
+fn indexed(frame: &Frame) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut colours: HashMap<Rgb, u8> = HashMap::new();
+    let mut palette = vec![];
+    let indices = pixels(frame)
+        .into_iter()
+        .map(|colour| {
+            if let Some(i) = colours.get(&colour) {
+                return Ok(*i);
+            }
+            let i = u8::try_from(colours.len()).map_err(|_| anyhow!("more than 256 colours"))?;
+            colours.insert(colour, i);
+            palette.extend([colour.0, colour.1, colour.2]);
+            Ok(i)
+        })
+        .collect::<Result<_>>()?;
+    Ok((indices, palette))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::visual::Glyph;
+
+    fn frame() -> Frame {
+        let mut frame = Frame::new(2, 2, Glyph::BLANK);
+        frame[(0, 0)] = Glyph::new('#', Rgb::RED);
+        frame[(1, 0)] = Glyph::new('#', Rgb::RED);
+        frame[(1, 1)] = Glyph::new('o', Rgb(1, 2, 3));
+        frame
+    }
+
+    #[test]
+    fn test_ansi() {
+        assert_eq!(
+            ansi(&frame()),
+            "\x1b[38;2;220;50;47m##\x1b[0m\n\x1b[38;2;0;0;0m \x1b[38;2;1;2;3mo\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_ppm() -> Result<()> {
+        let ppm = ppm(&frame())?;
+        let header = format!("P6\n{} {}\n255\n", 2 * SCALE, 2 * SCALE);
+        assert!(ppm.starts_with(header.as_bytes()));
+        assert_eq!(ppm.len(), header.len() + 3 * 4 * SCALE * SCALE);
+        // The last pixel is the bottom right corner of the `o`.
+        assert_eq!(ppm[ppm.len() - 3..], [1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_indexed() -> Result<()> {
+        let (indices, palette) = indexed(&frame())?;
+        assert_eq!(palette, [220, 50, 47, 0, 0, 0, 1, 2, 3]);
+        assert_eq!(indices[..2 * SCALE], [[0; SCALE], [0; SCALE]].concat());
+        assert_eq!(indices[indices.len() - 1], 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use anyhow::{anyhow, Context, Result};
+use aoc::answers::{same_answer, Answers};
+use aoc::days::Day;
+use aoc_common::output::Record;
+use aoc_common::solution::Part;
+use notify_debouncer_full::notify::RecursiveMode;
+use notify_debouncer_full::{new_debouncer, DebounceEventResult};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+// How long the files must stay untouched before the day is rerun.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Where a watched day's input comes from.
+pub enum Source {
+    /// The day's example.txt, with the answers and parameters in examples.toml.
+    Example,
+    /// The day's input.txt, with the answers in answers.toml.
+    Default,
+    /// Any other file, which has no recorded answers.
+    File(PathBuf),
+}
+
+/// Rebuilds and reruns the solved parts of `day` on its input now and whenever its `src/`
+/// directory or its input changes, printing how the answers compare with the previous run and
+/// with the recorded ones, for the day's own input or example. Runs until it is interrupted.
+pub fn watch(day: &Day, source: &Source, release: bool) -> Result<()> {
+    let (input, recorded) = match source {
+        Source::Example => (
+            day.example_input(),
+            Some(Answers::load(Answers::examples_path())?),
+        ),
+        Source::Default => (
+            day.default_input(),
+            Some(Answers::load(Answers::default_path())?),
+        ),
+        Source::File(path) => (path.clone(), None),
+    };
+    let input = input
+        .canonicalize()
+        .with_context(|| format!("cannot find {}", input.display()))?;
+    let src = day.dir().join("src").canonicalize()?;
+
+    let (sender, events) = mpsc::channel();
+    let mut debouncer = new_debouncer(DEBOUNCE, None, sender)?;
+    debouncer.watch(&src, RecursiveMode::Recursive)?;
+    // Editors often save by replacing the file, which ends a watch on the file itself.
+    debouncer.watch(input.parent().unwrap(), RecursiveMode::NonRecursive)?;
+    println!("watching {} and {}", src.display(), input.display());
+
+    let watched = |path: &Path| path.starts_with(&src) || path == input;
+    let recorded = recorded.as_ref();
+    let mut previous = rerun(day, source, release, &input, None, recorded)?;
+    while let Ok(batch) = events.recv() {
+        let mut changed = changes(batch, watched)?;
+        // Changes that settled at different times come in several batches: take them all at once.
+        for batch in events.try_iter() {
+            changed.extend(changes(batch, watched)?);
+        }
+        if changed.is_empty() {
+            continue;
+        }
+        changed.sort();
+        changed.dedup();
+        let names: Vec<String> = changed.iter().map(|p| p.display().to_string()).collect();
+        println!("\n{} changed", names.join(", "));
+        let records = rerun(day, source, release, &input, previous.as_deref(), recorded)?;
+        previous = records.or(previous);
+    }
+    Ok(())
+}
+
+// The watched files that a batch of events changed. Only changes count: the runs themselves read
+// the input, and the builds read the source.
+fn changes<F: Fn(&Path) -> bool>(batch: DebounceEventResult, watched: F) -> Result<Vec<PathBuf>> {
+    let batch = batch.map_err(|errors| anyhow!("cannot watch the files: {}", errors[0]))?;
+    Ok(batch
+        .into_iter()
+        .filter(|event| event.kind.is_create() || event.kind.is_modify() || event.kind.is_remove())
+        .flat_map(|event| event.event.paths)
+        .filter(|path| watched(path))
+        .collect())
+}
+
+// Runs the day in a fresh build of the runner and reports its answers, which it returns unless
+// the build failed.
+fn rerun(
+    day: &Day,
+    source: &Source,
+    release: bool,
+    input: &Path,
+    previous: Option<&[Record]>,
+    recorded: Option<&Answers>,
+) -> Result<Option<Vec<Record>>> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(aoc::workspace_root())
+        .args(["run", "--quiet", "--package", "aoc"]);
+    if release {
+        command.arg("--release");
+    }
+    command.args(["--", "run", &day.number.to_string(), "--format", "json"]);
+    match source {
+        Source::Example => command.arg("--example"),
+        Source::Default => &mut command,
+        Source::File(_) => command.arg("--input").arg(input),
+    };
+    // Build errors and diagnostics go straight to the terminal.
+    let output = command.stderr(Stdio::inherit()).output()?;
+
+    // The runner fails when a part does, but still prints a record for it.
+    let records = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<Vec<Record>, _>>()
+        .context("cannot read the answers")?;
+    if records.is_empty() {
+        println!("the build failed");
+        return Ok(None);
+    }
+    print!("{}", report(day.number, &records, previous, recorded));
+    Ok(Some(records))
+}
+
+// A line per record, noting whether its outcome changed since the `previous` run and whether it
+// matches the `recorded` answer, if there is one.
+fn report(
+    day: u32,
+    records: &[Record],
+    previous: Option<&[Record]>,
+    recorded: Option<&Answers>,
+) -> String {
+    let mut out = String::new();
+    for record in records {
+        let current = outcome(record);
+        let mut notes = vec![];
+        if let Some(previous) = previous {
+            notes.push(match previous.iter().find(|p| p.part == record.part) {
+                Some(p) if current == outcome(p) => "unchanged".to_string(),
+                Some(p) if !current.contains('\n') && !outcome(p).contains('\n') => {
+                    format!("was {}", outcome(p))
+                }
+                _ => "changed".to_string(),
+            });
+        }
+        if let (Some(part), Some(answer)) = (record.part, &record.answer) {
+            if let Some(expected) = recorded.and_then(|r| r.get(day, Part::try_from(part).unwrap()))
+            {
+                notes.push(if same_answer(expected, answer) {
+                    "matches the recorded answer".to_string()
+                } else {
+                    format!("the recorded answer is {}", expected.trim_matches('\n'))
+                });
+            }
+        }
+        notes.push(format!("{} ms", record.elapsed_ms));
+
+        let label = match record.part {
+            Some(part) => format!("part {}", part),
+            None => "input".to_string(),
+        };
+        if current.contains('\n') {
+            out.push_str(&format!("{} ({}):\n{}\n", label, notes.join(", "), current));
+        } else {
+            out.push_str(&format!("{}: {} ({})\n", label, current, notes.join(", ")));
+        }
+    }
+    out
+}
+
+// The answer, or else the error.
+fn outcome(record: &Record) -> String {
+    match (&record.answer, &record.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, Some(error)) => format!("error: {}", error),
+        (None, None) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn record(part: Part, answer: Result<String>) -> Record {
+        Record::new(
+            1,
+            Some(part),
+            answer,
+            Duration::from_millis(2),
+            Path::new("input.txt"),
+        )
+    }
+
+    #[test]
+    fn test_report() -> Result<()> {
+        let recorded = Answers::parse("[1]\npart1 = \"24000\"\npart2 = \"45000\"")?;
+        let first = [
+            record(Part::One, Ok("24000".to_string())),
+            record(Part::Two, Err(anyhow!("not yet"))),
+        ];
+        assert_eq!(
+            report(1, &first, None, Some(&recorded)),
+            "part 1: 24000 (matches the recorded answer, 2 ms)\n\
+             part 2: error: not yet (2 ms)\n"
+        );
+
+        let second = [
+            record(Part::One, Ok("24000".to_string())),
+            record(Part::Two, Ok("44000".to_string())),
+        ];
+        assert_eq!(
+            report(1, &second, Some(&first), Some(&recorded)),
+            "part 1: 24000 (unchanged, matches the recorded answer, 2 ms)\n\
+             part 2: 44000 (was error: not yet, the recorded answer is 45000, 2 ms)\n"
+        );
+        assert_eq!(
+            report(1, &second[..1], None, None),
+            "part 1: 24000 (2 ms)\n"
+        );
+        Ok(())
+    }
+}
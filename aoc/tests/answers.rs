@@ -0,0 +1,116 @@
+use aoc::answers::{same_answer, Answers};
+use aoc::days;
+use aoc_common::config::Config;
+use aoc_common::input::read_string;
+use aoc_common::solution::Part;
+use std::path::PathBuf;
+
+// Too slow for a debug build: only checked by `recorded_answers_slow`.
+const SLOW_DAYS: &[u32] = &[15];
+
+fn check<F: Fn(u32) -> bool>(answers: Answers, input: fn(&days::Day) -> PathBuf, filter: F) {
+    let config = Config::load(Config::default_path()).unwrap();
+    let mismatches: Vec<String> = std::thread::scope(|scope| {
+        let handles: Vec<_> = answers
+            .days()
+            .filter(|day| filter(*day))
+            .map(|day| {
+                let (answers, config) = (&answers, &config);
+                scope.spawn(move || check_day(answers, config, input, day))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    });
+
+    assert!(
+        mismatches.is_empty(),
+        "{} mismatches:\n{}",
+        mismatches.len(),
+        mismatches.join("\n")
+    );
+}
+
+fn check_day(
+    answers: &Answers,
+    config: &Config,
+    input: fn(&days::Day) -> PathBuf,
+    day: u32,
+) -> Vec<String> {
+    let solver = match days::find(day) {
+        Some(s) => s,
+        None => return vec![format!("day {}: no solution registered", day)],
+    };
+    let parts: Vec<Part> = Part::ALL
+        .into_iter()
+        .filter(|p| answers.get(day, *p).is_some())
+        .collect();
+    // A new day has nothing recorded yet, maybe not even its input.
+    if parts.is_empty() {
+        return vec![];
+    }
+
+    let mut config = config.get(day);
+    config.extend(answers.config(day).cloned().unwrap_or_default());
+
+    let results = read_string(input(solver)).and_then(|i| (solver.solve)(&i, &config, &parts));
+    match results {
+        Err(e) => vec![format!("day {}: {:#}", day, e)],
+        Ok(results) => results
+            .parts
+            .into_iter()
+            .filter_map(|(part, actual, _)| {
+                let expected = answers.get(day, part).unwrap();
+                match actual {
+                    Ok(a) if same_answer(expected, &a) => None,
+                    Ok(a) => Some(format!(
+                        "day {} part {}: expected {:?}, got {:?}",
+                        day, part, expected, a
+                    )),
+                    Err(e) => Some(format!(
+                        "day {} part {}: expected {:?}, got error: {:#}",
+                        day, part, expected, e
+                    )),
+                }
+            })
+            .collect(),
+    }
+}
+
+// `aoc new` adds an empty section for a new day, to fill in once its answers are accepted.
+#[test]
+fn every_day_has_recorded_answers() {
+    let answers = Answers::load(Answers::default_path()).unwrap();
+    for day in days::DAYS {
+        assert!(
+            answers.days().any(|d| d == day.number),
+            "day {} has no section in answers.toml",
+            day.number
+        );
+    }
+}
+
+#[test]
+fn recorded_answers() {
+    let answers = Answers::load(Answers::default_path()).unwrap();
+    check(answers, days::Day::default_input, |day| {
+        !SLOW_DAYS.contains(&day)
+    });
+}
+
+#[test]
+#[ignore = "slow in debug builds, run with `cargo test --release -- --ignored`"]
+fn recorded_answers_slow() {
+    let answers = Answers::load(Answers::default_path()).unwrap();
+    check(answers, days::Day::default_input, |day| {
+        SLOW_DAYS.contains(&day)
+    });
+}
+
+#[test]
+fn example_answers() {
+    let answers = Answers::load(Answers::examples_path()).unwrap();
+    check(answers, days::Day::example_input, |_| true);
+}
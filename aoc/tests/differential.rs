@@ -0,0 +1,113 @@
+// Runs every day's solution and a naive reference solver on many generated inputs, and reports
+// the smallest input on which their answers differ.
+
+mod reference;
+
+use aoc::days::{self, Day};
+use aoc::generate::generate;
+use aoc_common::solution::Part;
+use std::panic::{self, AssertUnwindSafe};
+use toml::Table;
+
+const MAX_SIZE: usize = 12;
+const SEEDS: u64 = 5;
+
+// Both solvers' answers, or `None` if they agree or the reference rejects the input.
+fn compare(day: &Day, input: &str, config: &Table) -> Option<(Vec<String>, Vec<String>)> {
+    let expected = reference::solver(day.number).unwrap()(input).ok()?;
+    let parts = &Part::ALL[..expected.len()];
+    // A panic is reported like any other wrong answer.
+    let actual = panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(input, config, parts)));
+    let actual: Vec<String> = match actual {
+        Ok(Ok(answers)) => answers
+            .parts
+            .into_iter()
+            .map(|(_, a, _)| a.unwrap_or_else(|e| format!("error: {:#}", e)))
+            .collect(),
+        Ok(Err(e)) => vec![format!("error: {:#}", e)],
+        Err(_) => vec!["panic".to_string()],
+    };
+    (actual != expected).then_some((expected, actual))
+}
+
+// Removes lines from a failing input for as long as it still fails, then shortens each line
+// that is left by removing runs of characters from it, halving the runs down to one character.
+// Some days' inputs are a single line.
+fn shrink(day: &Day, input: &str, config: &Table) -> String {
+    let fails = |lines: &[String]| compare(day, &(lines.join("\n") + "\n"), config).is_some();
+    let mut lines: Vec<String> = input.lines().map(str::to_string).collect();
+    let mut i = lines.len();
+    while i > 0 {
+        i -= 1;
+        let mut fewer = lines.clone();
+        fewer.remove(i);
+        if fails(&fewer) {
+            lines = fewer;
+            i = i.min(lines.len());
+        }
+    }
+    for i in 0..lines.len() {
+        let mut run = lines[i].chars().count() / 2;
+        while run > 0 {
+            let mut start = 0;
+            while start + run <= lines[i].chars().count() {
+                let mut shorter = lines.clone();
+                shorter[i] = lines[i]
+                    .chars()
+                    .enumerate()
+                    .filter(|(j, _)| !(start..start + run).contains(j))
+                    .map(|(_, c)| c)
+                    .collect();
+                if fails(&shorter) {
+                    lines = shorter;
+                } else {
+                    start += run;
+                }
+            }
+            run /= 2;
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+fn check(day: &Day) -> Option<String> {
+    // A new day has neither a reference solver nor a generator yet.
+    reference::solver(day.number)?;
+    let config: Table = reference::config(day.number).parse().unwrap();
+    for size in 1..=MAX_SIZE {
+        for seed in 0..SEEDS {
+            let input = generate(day.number, size, seed, &config).unwrap();
+            if compare(day, &input, &config).is_none() {
+                continue;
+            }
+            let input = shrink(day, &input, &config);
+            let (expected, actual) = compare(day, &input, &config).unwrap();
+            return Some(format!(
+                "day {} (aoc gen {} --size {} --seed {}), shrunk to:\n{}expected {:?}, got {:?}",
+                day.number, day.number, size, seed, input, expected, actual
+            ));
+        }
+    }
+    None
+}
+
+#[test]
+fn solutions_agree_with_references() {
+    let failures: Vec<String> = std::thread::scope(|scope| {
+        let handles: Vec<_> = days::DAYS
+            .iter()
+            .map(|day| scope.spawn(move || check(day)))
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|h| h.join().unwrap())
+            .collect()
+    });
+
+    assert!(
+        failures.is_empty(),
+        "{} days disagree:\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}
@@ -0,0 +1,677 @@
+// Naive solvers, written independently of the days' crates to check their answers against: they
+// simulate everything step by step and search exhaustively, and only need to be fast enough for
+// small generated inputs. Each returns its answers in part order, or an error if the input breaks
+// the puzzle's promises, so that shrinking a failing input never leaves the puzzle behind.
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub type Solver = fn(&str) -> Result<Vec<String>>;
+
+pub fn solver(day: u32) -> Option<Solver> {
+    Some(match day {
+        1 => day_01,
+        2 => day_02,
+        3 => day_03,
+        4 => day_04,
+        5 => day_05,
+        6 => day_06,
+        7 => day_07,
+        8 => day_08,
+        9 => day_09,
+        10 => day_10,
+        11 => day_11,
+        12 => day_12,
+        13 => day_13,
+        14 => day_14,
+        15 => day_15,
+        17 => day_17,
+        _ => return None,
+    })
+}
+
+/// The puzzle parameters the solvers assume, where they differ from the defaults.
+pub fn config(day: u32) -> &'static str {
+    match day {
+        15 => "row = 10\nbound = 20",
+        _ => "",
+    }
+}
+
+// Every integer in `s`, minus signs included.
+fn numbers(s: &str) -> Vec<i64> {
+    s.split(|c: char| !c.is_ascii_digit() && c != '-')
+        .filter_map(|n| n.parse().ok())
+        .collect()
+}
+
+fn day_01(input: &str) -> Result<Vec<String>> {
+    let mut elves = vec![0];
+    for l in input.lines() {
+        match l {
+            "" => elves.push(0),
+            n => *elves.last_mut().unwrap() += n.parse::<i64>()?,
+        }
+    }
+    elves.sort();
+    elves.reverse();
+    Ok(vec![
+        elves[0].to_string(),
+        elves.iter().take(3).sum::<i64>().to_string(),
+    ])
+}
+
+fn day_02(input: &str) -> Result<Vec<String>> {
+    // Scores of every line, read as shapes and as an outcome.
+    let scores = HashMap::from([
+        ("A X", (4, 3)),
+        ("A Y", (8, 4)),
+        ("A Z", (3, 8)),
+        ("B X", (1, 1)),
+        ("B Y", (5, 5)),
+        ("B Z", (9, 9)),
+        ("C X", (7, 2)),
+        ("C Y", (2, 6)),
+        ("C Z", (6, 7)),
+    ]);
+    let (mut part1, mut part2) = (0, 0);
+    for l in input.lines() {
+        let (a, b) = scores.get(l).context("invalid line")?;
+        part1 += a;
+        part2 += b;
+    }
+    Ok(vec![part1.to_string(), part2.to_string()])
+}
+
+fn priority(c: char) -> u32 {
+    match c {
+        'a'..='z' => c as u32 - 'a' as u32 + 1,
+        _ => c as u32 - 'A' as u32 + 27,
+    }
+}
+
+// The only item of `s` found in all of `others`.
+fn only_common(s: &str, others: &[&str]) -> Result<u32> {
+    let common: HashSet<char> = s
+        .chars()
+        .filter(|c| others.iter().all(|o| o.contains(*c)))
+        .collect();
+    match common.into_iter().collect::<Vec<_>>()[..] {
+        [c] => Ok(priority(c)),
+        _ => bail!("not exactly one common item"),
+    }
+}
+
+fn day_03(input: &str) -> Result<Vec<String>> {
+    let lines: Vec<&str> = input.lines().collect();
+    if !lines.len().is_multiple_of(3) {
+        bail!("incomplete group");
+    }
+    let mut part1 = 0;
+    for l in &lines {
+        let (left, right) = l.split_at(l.len() / 2);
+        part1 += only_common(left, &[right])?;
+    }
+    let mut part2 = 0;
+    for group in lines.chunks(3) {
+        part2 += only_common(group[0], &group[1..])?;
+    }
+    Ok(vec![part1.to_string(), part2.to_string()])
+}
+
+fn day_04(input: &str) -> Result<Vec<String>> {
+    let (mut part1, mut part2) = (0, 0);
+    for l in input.lines() {
+        let [a, b, c, d] = numbers(&l.replace('-', " "))[..] else {
+            bail!("invalid line");
+        };
+        let left: HashSet<i64> = (a..=b).collect();
+        let right: HashSet<i64> = (c..=d).collect();
+        if left.is_subset(&right) || right.is_subset(&left) {
+            part1 += 1;
+        }
+        if !left.is_disjoint(&right) {
+            part2 += 1;
+        }
+    }
+    Ok(vec![part1.to_string(), part2.to_string()])
+}
+
+fn day_05(input: &str) -> Result<Vec<String>> {
+    let (drawing, moves) = input.split_once("\n\n").context("no moves")?;
+    let rows: Vec<Vec<char>> = drawing.lines().map(|l| l.chars().collect()).collect();
+    let count = numbers(drawing.lines().last().unwrap()).len();
+    // Bottom crate first.
+    let mut stacks = vec![vec![]; count];
+    for row in rows.iter().rev().skip(1) {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match row.get(1 + 4 * i) {
+                Some(c) if c.is_ascii_alphabetic() => stack.push(*c),
+                _ => {}
+            }
+        }
+    }
+    let mut crane_9000 = stacks.clone();
+    let mut crane_9001 = stacks;
+    for l in moves.lines() {
+        let [n, from, to] = numbers(l)[..] else {
+            bail!("invalid move");
+        };
+        if ![from, to].iter().all(|s| (1..=count as i64).contains(s)) {
+            bail!("no such stack");
+        }
+        let (n, from, to) = (n as usize, from as usize - 1, to as usize - 1);
+        for _ in 0..n {
+            let c = crane_9000[from].pop().context("empty stack")?;
+            crane_9000[to].push(c);
+        }
+        let at = crane_9001[from]
+            .len()
+            .checked_sub(n)
+            .context("empty stack")?;
+        let lifted = crane_9001[from].split_off(at);
+        crane_9001[to].extend(lifted);
+    }
+    let tops = |stacks: Vec<Vec<char>>| stacks.iter().filter_map(|s| s.last()).collect();
+    Ok(vec![tops(crane_9000), tops(crane_9001)])
+}
+
+fn day_06(input: &str) -> Result<Vec<String>> {
+    let chars: Vec<char> = input.chars().collect();
+    let marker = |size: usize| {
+        (size..=chars.len())
+            .find(|&end| chars[end - size..end].iter().collect::<HashSet<_>>().len() == size)
+            .map(|end| end.to_string())
+            .context("no marker")
+    };
+    Ok(vec![marker(4)?, marker(14)?])
+}
+
+// Sums the files below every directory, rather than adding each directory to its parent.
+fn day_07(input: &str) -> Result<Vec<String>> {
+    if !input.starts_with("$ cd /\n") {
+        bail!("the transcript does not start at the root");
+    }
+    let mut cwd: Vec<&str> = vec![];
+    let mut dirs: HashSet<Vec<&str>> = HashSet::from([vec![]]);
+    let mut files: HashMap<Vec<&str>, u64> = HashMap::new();
+    for l in input.lines().skip(1) {
+        match l.split(' ').collect::<Vec<_>>()[..] {
+            ["$", "cd", "/"] => bail!("the transcript goes back to the root"),
+            ["$", "cd", ".."] => {
+                cwd.pop().context("cannot leave the root")?;
+            }
+            ["$", "cd", dir] => {
+                cwd.push(dir);
+                if !dirs.insert(cwd.clone()) {
+                    bail!("directory entered twice");
+                }
+            }
+            ["$", "ls"] | ["dir", _] => {}
+            [size, name] => {
+                let mut path = cwd.clone();
+                path.push(name);
+                files.insert(path, size.parse()?);
+            }
+            _ => bail!("invalid line"),
+        }
+    }
+    let sizes: Vec<u64> = dirs
+        .iter()
+        .map(|d| {
+            files
+                .iter()
+                .filter(|(f, _)| f.starts_with(d))
+                .map(|(_, s)| s)
+                .sum()
+        })
+        .collect();
+    let used: u64 = files.values().sum();
+    let needed = (30_000_000 + used).saturating_sub(70_000_000);
+    Ok(vec![
+        sizes
+            .iter()
+            .filter(|s| **s <= 100_000)
+            .sum::<u64>()
+            .to_string(),
+        sizes
+            .iter()
+            .filter(|s| **s >= needed)
+            .min()
+            .context("no directory big enough")?
+            .to_string(),
+    ])
+}
+
+fn day_08(input: &str) -> Result<Vec<String>> {
+    let grid: Vec<Vec<u8>> = input.lines().map(|l| l.bytes().collect()).collect();
+    let (h, w) = (grid.len() as i64, grid[0].len() as i64);
+    let (mut visible, mut best) = (0, 0);
+    for y in 0..h {
+        for x in 0..w {
+            let tree = grid[y as usize][x as usize];
+            let mut seen_from_outside = false;
+            let mut score = 1;
+            for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                let (mut cx, mut cy, mut distance) = (x + dx, y + dy, 0);
+                let mut blocked = false;
+                while (0..w).contains(&cx) && (0..h).contains(&cy) {
+                    distance += 1;
+                    if grid[cy as usize][cx as usize] >= tree {
+                        blocked = true;
+                        break;
+                    }
+                    (cx, cy) = (cx + dx, cy + dy);
+                }
+                seen_from_outside |= !blocked;
+                score *= distance;
+            }
+            visible += seen_from_outside as u32;
+            best = best.max(score);
+        }
+    }
+    Ok(vec![visible.to_string(), best.to_string()])
+}
+
+fn day_09(input: &str) -> Result<Vec<String>> {
+    let tail_positions = |knots: usize| -> Result<usize> {
+        let mut rope = vec![(0i64, 0i64); knots];
+        let mut seen = HashSet::from([(0, 0)]);
+        for l in input.lines() {
+            let (d, n) = l.split_once(' ').context("invalid move")?;
+            let (dx, dy) = match d {
+                "U" => (0, -1),
+                "D" => (0, 1),
+                "L" => (-1, 0),
+                "R" => (1, 0),
+                _ => bail!("invalid direction"),
+            };
+            for _ in 0..n.parse::<u32>()? {
+                rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+                for i in 1..knots {
+                    let (hx, hy) = rope[i - 1];
+                    let (tx, ty) = &mut rope[i];
+                    if (hx - *tx).abs() > 1 || (hy - *ty).abs() > 1 {
+                        *tx += (hx - *tx).signum();
+                        *ty += (hy - *ty).signum();
+                    }
+                }
+                seen.insert(rope[knots - 1]);
+            }
+        }
+        Ok(seen.len())
+    };
+    Ok(vec![
+        tail_positions(2)?.to_string(),
+        tail_positions(10)?.to_string(),
+    ])
+}
+
+fn day_10(input: &str) -> Result<Vec<String>> {
+    // The value of X during every cycle.
+    let mut during = vec![];
+    let mut x = 1;
+    for l in input.lines() {
+        match l.split_once(' ') {
+            None if l == "noop" => during.push(x),
+            Some(("addx", n)) => {
+                during.extend([x, x]);
+                x += n.parse::<i64>()?;
+            }
+            _ => bail!("invalid instruction"),
+        }
+    }
+    let strength = [20, 60, 100, 140, 180, 220]
+        .iter()
+        .map(|c| during.get(c - 1).map(|x| *c as i64 * x))
+        .sum::<Option<i64>>()
+        .context("program too short")?;
+    let mut screen = String::new();
+    for (cycle, x) in during.iter().enumerate() {
+        let column = (cycle % 40) as i64;
+        if column == 0 {
+            screen.push('\n');
+        }
+        screen.push(if (column - x).abs() <= 1 { '#' } else { '.' });
+    }
+    screen.push('\n');
+    Ok(vec![strength.to_string(), screen])
+}
+
+struct Monkey {
+    items: Vec<u64>,
+    operation: Vec<String>,
+    divisor: u64,
+    targets: [usize; 2],
+}
+
+// Part 2 keeps, for every item, its worry level modulo each monkey's divisor separately.
+fn day_11(input: &str) -> Result<Vec<String>> {
+    let monkeys = input
+        .split("\n\n")
+        .map(|block| {
+            let lines: Vec<&str> = block.lines().collect();
+            let [_, items, operation, test, if_true, if_false] = lines[..] else {
+                bail!("invalid monkey");
+            };
+            let last = |l: &str| numbers(l).last().copied().context("missing number");
+            Ok(Monkey {
+                items: numbers(items).iter().map(|i| *i as u64).collect(),
+                operation: operation
+                    .split_whitespace()
+                    .skip(3)
+                    .map(str::to_string)
+                    .collect(),
+                divisor: last(test)? as u64,
+                targets: [last(if_true)? as usize, last(if_false)? as usize],
+            })
+        })
+        .collect::<Result<Vec<Monkey>>>()?;
+    let apply = |m: &Monkey, old: u64, modulo: Option<u64>| {
+        let arg = |a: &str| a.parse().unwrap_or(old);
+        let (a, b) = (arg(&m.operation[0]), arg(&m.operation[2]));
+        let new = match m.operation[1].as_str() {
+            "*" => a as u128 * b as u128,
+            _ => a as u128 + b as u128,
+        };
+        match modulo {
+            Some(n) => Ok((new % n as u128) as u64),
+            None => u64::try_from(new).context("worry level overflow"),
+        }
+    };
+    let business = |mut inspections: Vec<u64>| {
+        inspections.sort();
+        inspections.reverse();
+        inspections[0] * inspections[1]
+    };
+
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..20 {
+        for (i, m) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                inspections[i] += 1;
+                let item = apply(m, item, None)? / 3;
+                items[m.targets[(item % m.divisor != 0) as usize]].push(item);
+            }
+        }
+    }
+    let part1 = business(inspections);
+
+    let residues = |item: u64| monkeys.iter().map(|m| item % m.divisor).collect();
+    let mut items: Vec<Vec<Vec<u64>>> = monkeys
+        .iter()
+        .map(|m| m.items.iter().map(|i| residues(*i)).collect())
+        .collect();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..10_000 {
+        for (i, m) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                inspections[i] += 1;
+                let item: Vec<u64> = item
+                    .iter()
+                    .zip(&monkeys)
+                    .map(|(r, n)| apply(m, *r, Some(n.divisor)))
+                    .collect::<Result<_>>()?;
+                items[m.targets[(item[i] != 0) as usize]].push(item);
+            }
+        }
+    }
+    Ok(vec![part1.to_string(), business(inspections).to_string()])
+}
+
+// Breadth-first search from every start separately.
+fn day_12(input: &str) -> Result<Vec<String>> {
+    let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let height = |c: char| match c {
+        'S' => 'a',
+        'E' => 'z',
+        c => c,
+    } as i32;
+    let cells = || (0..grid.len()).flat_map(|y| (0..grid[y].len()).map(move |x| (x, y)));
+    let steps = |start: (usize, usize)| {
+        let mut seen = HashSet::from([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some(((x, y), n)) = queue.pop_front() {
+            if grid[y][x] == 'E' {
+                return Some(n);
+            }
+            for (nx, ny) in [
+                (x + 1, y),
+                (x.wrapping_sub(1), y),
+                (x, y + 1),
+                (x, y.wrapping_sub(1)),
+            ] {
+                let Some(&c) = grid.get(ny).and_then(|r| r.get(nx)) else {
+                    continue;
+                };
+                if height(c) <= height(grid[y][x]) + 1 && seen.insert((nx, ny)) {
+                    queue.push_back(((nx, ny), n + 1));
+                }
+            }
+        }
+        None
+    };
+    let start = cells()
+        .find(|(x, y)| grid[*y][*x] == 'S')
+        .context("no start")?;
+    Ok(vec![
+        steps(start).context("no path")?.to_string(),
+        cells()
+            .filter(|(x, y)| height(grid[*y][*x]) == 'a' as i32)
+            .filter_map(steps)
+            .min()
+            .context("no path")?
+            .to_string(),
+    ])
+}
+
+#[derive(Clone)]
+enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+fn packet(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<Packet> {
+    if chars.peek() != Some(&'[') {
+        let mut n = String::new();
+        while let Some(c) = chars.next_if(char::is_ascii_digit) {
+            n.push(c);
+        }
+        return Ok(Packet::Integer(n.parse()?));
+    }
+    chars.next();
+    let mut list = vec![];
+    while chars.peek() != Some(&']') {
+        list.push(packet(chars)?);
+        chars.next_if_eq(&',');
+    }
+    chars.next();
+    Ok(Packet::List(list))
+}
+
+// Whether `left` comes before `right`, or `None` if the comparison is undecided.
+fn in_order(left: &Packet, right: &Packet) -> Option<bool> {
+    match (left, right) {
+        (Packet::Integer(l), Packet::Integer(r)) => (l != r).then_some(l < r),
+        (Packet::Integer(_), _) => in_order(&Packet::List(vec![left.clone()]), right),
+        (_, Packet::Integer(_)) => in_order(left, &Packet::List(vec![right.clone()])),
+        (Packet::List(l), Packet::List(r)) => {
+            for (a, b) in l.iter().zip(r) {
+                if let Some(o) = in_order(a, b) {
+                    return Some(o);
+                }
+            }
+            (l.len() != r.len()).then_some(l.len() < r.len())
+        }
+    }
+}
+
+fn day_13(input: &str) -> Result<Vec<String>> {
+    if input
+        .trim_end()
+        .split("\n\n")
+        .any(|b| b.lines().count() != 2)
+    {
+        bail!("not a pair of packets");
+    }
+    let packets = input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| packet(&mut l.chars().peekable()))
+        .collect::<Result<Vec<_>>>()?;
+    let part1: usize = packets
+        .chunks(2)
+        .zip(1..)
+        .filter(|(pair, _)| in_order(&pair[0], &pair[1]) == Some(true))
+        .map(|(_, i)| i)
+        .sum();
+    // Positions of the dividers once sorted: one more than the packets before each.
+    let dividers = ["[[2]]", "[[6]]"].map(|d| packet(&mut d.chars().peekable()).unwrap());
+    let before = |d: &Packet| {
+        packets
+            .iter()
+            .filter(|p| in_order(p, d) == Some(true))
+            .count()
+    };
+    let part2 = (before(&dividers[0]) + 1) * (before(&dividers[1]) + 2);
+    Ok(vec![part1.to_string(), part2.to_string()])
+}
+
+fn day_14(input: &str) -> Result<Vec<String>> {
+    let mut rocks = HashSet::new();
+    for l in input.lines() {
+        let points: Vec<(i64, i64)> = l
+            .split(" -> ")
+            .map(|p| match numbers(p)[..] {
+                [x, y] => Ok((x, y)),
+                _ => Err(anyhow!("invalid point")),
+            })
+            .collect::<Result<_>>()?;
+        for w in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+            for x in x0.min(x1)..=x0.max(x1) {
+                for y in y0.min(y1)..=y0.max(y1) {
+                    rocks.insert((x, y));
+                }
+            }
+        }
+        rocks.extend(points.first());
+    }
+    let bottom = rocks.iter().map(|r| r.1).max().context("no rocks")?;
+
+    let count = |floor: bool| {
+        let mut blocked = rocks.clone();
+        let mut grains = 0;
+        while !blocked.contains(&(500, 0)) {
+            let (mut x, mut y) = (500, 0);
+            loop {
+                if y == bottom + 1 {
+                    if !floor {
+                        return grains;
+                    }
+                    break;
+                }
+                match [x, x - 1, x + 1]
+                    .into_iter()
+                    .find(|nx| !blocked.contains(&(*nx, y + 1)))
+                {
+                    Some(nx) => (x, y) = (nx, y + 1),
+                    None => break,
+                }
+            }
+            blocked.insert((x, y));
+            grains += 1;
+        }
+        grains
+    };
+    Ok(vec![count(false).to_string(), count(true).to_string()])
+}
+
+// Checks every position in the row, and in the search area, against every sensor.
+fn day_15(input: &str) -> Result<Vec<String>> {
+    let (row, bound) = (10, 20);
+    let reports = input
+        .lines()
+        .map(|l| match numbers(l)[..] {
+            [sx, sy, bx, by] => Ok(((sx, sy), (bx, by))),
+            _ => Err(anyhow!("invalid report")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let distance = |(ax, ay): (i64, i64), (bx, by): (i64, i64)| (ax - bx).abs() + (ay - by).abs();
+    let covered = |p| {
+        reports
+            .iter()
+            .any(|(s, b)| distance(*s, p) <= distance(*s, *b))
+    };
+
+    let reach = reports
+        .iter()
+        .map(|(s, b)| distance(*s, *b))
+        .max()
+        .unwrap_or(0);
+    let xs = reports.iter().map(|(s, _)| s.0);
+    let (left, right) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+    let part1 = (left - reach..=right + reach)
+        .filter(|x| covered((*x, row)) && !reports.iter().any(|(_, b)| *b == (*x, row)))
+        .count();
+    let gaps: Vec<(i64, i64)> = (0..=bound)
+        .flat_map(|x| (0..=bound).map(move |y| (x, y)))
+        .filter(|p| !covered(*p))
+        .collect();
+    let [beacon] = gaps[..] else {
+        bail!("{} positions for the distress beacon", gaps.len());
+    };
+    Ok(vec![
+        part1.to_string(),
+        (beacon.0 * 4_000_000 + beacon.1).to_string(),
+    ])
+}
+
+fn day_17(input: &str) -> Result<Vec<String>> {
+    let rocks: [&[(i64, i64)]; 5] = [
+        &[(0, 0), (1, 0), (2, 0), (3, 0)],
+        &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+        &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+        &[(0, 0), (0, 1), (0, 2), (0, 3)],
+        &[(0, 0), (1, 0), (0, 1), (1, 1)],
+    ];
+    let jets: Vec<i64> = input
+        .trim_end()
+        .chars()
+        .map(|c| match c {
+            '<' => Ok(-1),
+            '>' => Ok(1),
+            _ => Err(anyhow!("invalid jet")),
+        })
+        .collect::<Result<_>>()?;
+    if jets.is_empty() {
+        bail!("no jets");
+    }
+    let mut settled: HashSet<(i64, i64)> = HashSet::new();
+    let (mut top, mut jet) = (0, 0);
+    for i in 0..2022 {
+        let rock = rocks[i % 5];
+        let (mut x, mut y) = (2, top + 3);
+        let fits = |x: i64, y: i64| {
+            rock.iter().all(|(dx, dy)| {
+                (0..7).contains(&(x + dx)) && y + dy >= 0 && !settled.contains(&(x + dx, y + dy))
+            })
+        };
+        loop {
+            if fits(x + jets[jet % jets.len()], y) {
+                x += jets[jet % jets.len()];
+            }
+            jet += 1;
+            if !fits(x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+        for (dx, dy) in rock {
+            settled.insert((x + dx, y + dy));
+            top = top.max(y + dy + 1);
+        }
+    }
+    Ok(vec![top.to_string()])
+}
@@ -0,0 +1,160 @@
+use aoc::answers::Answers;
+use aoc::serve::Server;
+use aoc_common::config::Config;
+use aoc_common::input::read_string;
+use aoc_common::solution::Part;
+use serde_json::Value;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::OnceLock;
+
+// One server for every test, on a free port, answering until the tests are over.
+fn server() -> SocketAddr {
+    static ADDR: OnceLock<SocketAddr> = OnceLock::new();
+    *ADDR.get_or_init(|| {
+        let server = Server::bind(0, Config::load(Config::default_path()).unwrap()).unwrap();
+        let addr = server.addr();
+        std::thread::spawn(move || server.run());
+        addr
+    })
+}
+
+// Sends a request to the server, returning the status and the JSON body of its response. Over
+// HTTP/1.0, long responses come whole rather than in chunks.
+fn request(method: &str, url: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(server()).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.0\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        url,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+fn example(day: u32) -> String {
+    let dir = aoc::workspace_root().join(format!("day-{:02}", day));
+    read_string(dir.join("example.txt")).unwrap()
+}
+
+#[test]
+fn solves_both_parts() {
+    let examples = Answers::load(Answers::examples_path()).unwrap();
+    let (status, body) = request("POST", "/days/1", &example(1));
+
+    assert_eq!(status, 200);
+    assert_eq!(body["day"], 1);
+    assert!(body["parse_ms"].as_f64().unwrap() >= 0.0);
+    let parts = body["parts"].as_array().unwrap();
+    assert_eq!(parts.len(), 2);
+    for (part, reply) in Part::ALL.iter().zip(parts) {
+        assert_eq!(reply["part"], part.number());
+        assert_eq!(reply["answer"], examples.get(1, *part).unwrap());
+        assert!(reply["elapsed_ms"].as_f64().unwrap() >= 0.0);
+    }
+}
+
+#[test]
+fn solves_one_part_with_overrides() {
+    // The example needs a different row from the one in aoc.toml.
+    let (status, body) = request("POST", "/days/15/parts/1?row=10", &example(15));
+
+    assert_eq!(status, 200);
+    assert_eq!(body["parts"].as_array().unwrap().len(), 1);
+    assert_eq!(body["parts"][0]["answer"], "26");
+}
+
+#[test]
+fn skips_unsolved_parts() {
+    let (status, body) = request("POST", "/days/17", &example(17));
+
+    assert_eq!(status, 200);
+    let parts = body["parts"].as_array().unwrap();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0]["part"], 1);
+    assert_eq!(request("POST", "/days/17/parts/2", &example(17)).0, 404);
+}
+
+#[test]
+fn lists_days() {
+    let (status, body) = request("GET", "/days", "");
+
+    assert_eq!(status, 200);
+    assert_eq!(body[0], 1);
+    assert!(!body.as_array().unwrap().contains(&Value::from(16)));
+}
+
+#[test]
+fn reports_parse_errors() {
+    let (status, body) = request("POST", "/days/1/parts/1", "1000\n2000\nabc\n");
+
+    assert_eq!(status, 400);
+    let error = &body["error"];
+    assert_eq!(error["line"], 3);
+    assert_eq!(error["column"], 1);
+    assert!(!error["message"].as_str().unwrap().is_empty());
+    assert!(error["diagnostic"].as_str().unwrap().contains("3 | abc\n"));
+}
+
+#[test]
+fn reports_part_errors() {
+    let (status, body) = request("POST", "/days/1", "");
+
+    assert_eq!(status, 422);
+    assert_eq!(body["parts"][0]["error"]["message"], "empty input");
+}
+
+#[test]
+fn rejects_long_inputs() {
+    let (status, body) = request("POST", "/days/1", &"1\n".repeat(600_000));
+
+    assert_eq!(status, 413);
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("longer"));
+}
+
+#[test]
+fn rejects_bad_requests() {
+    let status = |method, url| request(method, url, "1\n").0;
+
+    assert_eq!(status("POST", "/days/16"), 404);
+    assert_eq!(status("POST", "/days/1/parts/3"), 404);
+    assert_eq!(status("POST", "/days"), 405);
+    assert_eq!(status("GET", "/days/1/parts/1"), 405);
+    assert_eq!(status("POST", "/nowhere"), 404);
+    assert_eq!(status("POST", "/days/1?row"), 400);
+}
+
+#[test]
+fn rejects_packets_nested_too_deep() {
+    let deep = format!("[]\n{}{}\n", "[".repeat(200_000), "]".repeat(200_000));
+    let (status, body) = request("POST", "/days/13", &deep);
+
+    assert_eq!(status, 400);
+    assert_eq!(body["error"]["line"], 2);
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("nested deeper"));
+}
+
+#[test]
+fn rejects_parameters_too_large_to_solve() {
+    let status = |day, query| request("POST", &format!("/days/{}?{}", day, query), &example(day)).0;
+
+    assert_eq!(status(9, "knots_part1=1000000000000"), 400);
+    assert_eq!(status(14, "source=[500,9223372036854775807]"), 400);
+    assert_eq!(status(17, "rocks=1000000000000"), 400);
+    assert_eq!(status(17, "chamber_width=1000000000000"), 400);
+    assert_eq!(status(17, "rocks=0"), 400);
+}
@@ -0,0 +1,61 @@
+//! Day 1: Calorie Counting. [`Day01`] parses the Elves' inventories into the calories each Elf
+//! carries, most first; part 1 is the most any Elf carries and part 2 what the top three carry.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::input::split_blocks;
+use aoc_common::parse::{parse_token, ParseError};
+use aoc_common::solution::Solution;
+
+/// Finds the Elves carrying the most calories.
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Config = ();
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        let mut sums = split_blocks(input)
+            .map(|b| {
+                b.lines().try_fold(0i32, |sum, n| {
+                    sum.checked_add(parse_token(input, n)?)
+                        .ok_or_else(|| ParseError::new(input, b, "too many calories"))
+                })
+            })
+            .collect::<Result<Vec<i32>, _>>()?;
+        sums.sort_by(|a, b| b.cmp(a));
+        Ok(sums)
+    }
+
+    fn part1(sums: &Vec<i32>, _: &()) -> Result<i32> {
+        sums.first().copied().ok_or(anyhow!("empty input"))
+    }
+
+    fn part2(sums: &Vec<i32>, _: &()) -> Result<i32> {
+        Ok(sums.iter().take(3).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day01::parse(EXAMPLE)?;
+        assert_eq!(Day01::part1(&input, &())?, 24000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day01::parse(EXAMPLE)?;
+        assert_eq!(Day01::part2(&input, &())?, 45000);
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+//! Day 2: Rock Paper Scissors. [`Day02`] parses the strategy guide into one [`Entry`] per round
+//! and scores it, reading the second column as the shape to play in part 1 and as the outcome
+//! to aim for in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::input::parse_lines;
+use aoc_common::parse::{ParseError, Words};
+use aoc_common::solution::Solution;
+
+/// A shape a player can throw, written A, B or C for the opponent.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Shape {
+    /// Beats scissors, worth 1 point.
+    Rock,
+    /// Beats rock, worth 2 points.
+    Paper,
+    /// Beats paper, worth 3 points.
+    Scissors,
+}
+
+impl std::str::FromStr for Shape {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Shape::Rock),
+            "B" => Ok(Shape::Paper),
+            "C" => Ok(Shape::Scissors),
+            _ => Err(anyhow!("expected A, B or C")),
+        }
+    }
+}
+
+impl Shape {
+    fn score(&self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    fn against(&self, other: &Shape) -> Outcome {
+        if self == other {
+            return Outcome::Draw;
+        }
+        match (self, other) {
+            (Shape::Rock, Shape::Scissors) => Outcome::Win,
+            (Shape::Paper, Shape::Rock) => Outcome::Win,
+            (Shape::Scissors, Shape::Paper) => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    fn wins_against(&self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    fn loses_against(&self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Round {
+    me: Shape,
+    opponent: Shape,
+}
+
+impl Round {
+    fn score(&self) -> u32 {
+        self.me.score() + self.me.against(&self.opponent).score()
+    }
+}
+
+enum Outcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+/// The second column of the strategy guide, which part 1 reads as a shape and part 2 as an
+/// outcome.
+#[derive(Debug, Clone, Copy)]
+pub enum Column {
+    /// Rock, or a loss.
+    X,
+    /// Paper, or a draw.
+    Y,
+    /// Scissors, or a win.
+    Z,
+}
+
+impl std::str::FromStr for Column {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Column::X),
+            "Y" => Ok(Column::Y),
+            "Z" => Ok(Column::Z),
+            _ => Err(anyhow!("expected X, Y or Z")),
+        }
+    }
+}
+
+impl Column {
+    fn shape(self) -> Shape {
+        match self {
+            Column::X => Shape::Rock,
+            Column::Y => Shape::Paper,
+            Column::Z => Shape::Scissors,
+        }
+    }
+
+    fn outcome(self) -> Outcome {
+        match self {
+            Column::X => Outcome::Lose,
+            Column::Y => Outcome::Draw,
+            Column::Z => Outcome::Win,
+        }
+    }
+}
+
+impl Outcome {
+    fn score(&self) -> u32 {
+        match self {
+            Outcome::Win => 6,
+            Outcome::Draw => 3,
+            Outcome::Lose => 0,
+        }
+    }
+}
+
+struct NewRound {
+    opponent: Shape,
+    expected: Outcome,
+}
+
+/// A line of the strategy guide.
+pub struct Entry {
+    /// The shape the opponent will throw.
+    pub opponent: Shape,
+    /// What the guide says to answer.
+    pub column: Column,
+}
+
+impl std::str::FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Words::new(s);
+        let entry = Entry {
+            opponent: words.parse("the opponent's shape")?,
+            column: words.parse("X, Y or Z")?,
+        };
+        words.end()?;
+        Ok(entry)
+    }
+}
+
+impl NewRound {
+    fn score(&self) -> u32 {
+        let me = match self.expected {
+            Outcome::Win => self.opponent.loses_against(),
+            Outcome::Draw => self.opponent.clone(),
+            Outcome::Lose => self.opponent.wins_against(),
+        };
+        me.score() + self.expected.score()
+    }
+}
+
+/// Scores the rounds of a rock paper scissors tournament.
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Entry>;
+    type Config = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Entry>> {
+        parse_lines(input)
+    }
+
+    fn part1(entries: &Vec<Entry>, _: &()) -> Result<u32> {
+        Ok(entries
+            .iter()
+            .map(|e| Round {
+                me: e.column.shape(),
+                opponent: e.opponent.clone(),
+            })
+            .map(|r| r.score())
+            .sum())
+    }
+
+    fn part2(entries: &Vec<Entry>, _: &()) -> Result<u32> {
+        Ok(entries
+            .iter()
+            .map(|e| NewRound {
+                opponent: e.opponent.clone(),
+                expected: e.column.outcome(),
+            })
+            .map(|r| r.score())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day02::parse(EXAMPLE)?;
+        assert_eq!(Day02::part1(&input, &())?, 15);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day02::parse(EXAMPLE)?;
+        assert_eq!(Day02::part2(&input, &())?, 12);
+        Ok(())
+    }
+
+    fn shape() -> impl Strategy<Value = Shape> {
+        prop_oneof![Just(Shape::Rock), Just(Shape::Paper), Just(Shape::Scissors)]
+    }
+
+    proptest! {
+        #[test]
+        fn wins_and_loses_against_are_inverses(s in shape()) {
+            prop_assert_eq!(s.wins_against().loses_against(), s.clone());
+            prop_assert_eq!(s.loses_against().wins_against(), s);
+        }
+
+        #[test]
+        fn against_agrees_with_wins_and_loses(s in shape()) {
+            prop_assert!(matches!(s.against(&s.wins_against()), Outcome::Win));
+            prop_assert!(matches!(s.against(&s.loses_against()), Outcome::Lose));
+            prop_assert!(matches!(s.against(&s), Outcome::Draw));
+        }
+    }
+}
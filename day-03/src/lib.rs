@@ -0,0 +1,104 @@
+//! Day 3: Rucksack Reorganization. [`Day03`] parses each rucksack into the string of its items,
+//! and sums the priorities of the item common to both halves of each rucksack in part 1, and of
+//! the badge common to each group of three in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{bail, Result};
+use aoc_common::parse::ParseError;
+use aoc_common::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+/// Finds the items the Elves packed wrong, and their badges.
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Config = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        input.lines().map(|l| parse_rucksack(input, l)).collect()
+    }
+
+    fn part1(input: &Vec<String>, _: &()) -> Result<u32> {
+        let priorities = priorities();
+        Ok(input
+            .iter()
+            .map(split_in_half)
+            .filter_map(common_char)
+            .filter_map(|c| priorities.get(&c))
+            .sum())
+    }
+
+    fn part2(input: &Vec<String>, _: &()) -> Result<u32> {
+        let priorities = priorities();
+        Ok(input
+            .chunks(3)
+            .map(Vec::from)
+            .filter_map(common_char)
+            .filter_map(|c| priorities.get(&c))
+            .sum())
+    }
+}
+
+fn parse_rucksack(input: &str, line: &str) -> Result<String> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        let item = &line[i..i + c.len_utf8()];
+        bail!(ParseError::new(
+            input,
+            item,
+            format!("'{}' is not an item", c)
+        ));
+    }
+    if !line.len().is_multiple_of(2) {
+        bail!(ParseError::new(
+            input,
+            line,
+            "compartments of different sizes"
+        ));
+    }
+    Ok(line.to_string())
+}
+
+fn priorities() -> HashMap<char, u32> {
+    ('a'..='z')
+        .zip(1..=26)
+        .chain(('A'..='Z').zip(27..=52))
+        .collect()
+}
+
+fn split_in_half<S: AsRef<str>>(s: S) -> [String; 2] {
+    let (left, right) = s.as_ref().split_at(s.as_ref().len() / 2);
+    [left.to_string(), right.to_string()]
+}
+
+fn common_char<I: IntoIterator<Item = String>>(strings: I) -> Option<char> {
+    let intersection = strings
+        .into_iter()
+        .map(|s| s.chars().collect::<HashSet<char>>())
+        .reduce(|i, g| &i & &g)?;
+    intersection.into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day03::parse(EXAMPLE)?;
+        assert_eq!(Day03::part1(&input, &())?, 157);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day03::parse(EXAMPLE)?;
+        assert_eq!(Day03::part2(&input, &())?, 70);
+        Ok(())
+    }
+}
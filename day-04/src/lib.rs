@@ -0,0 +1,144 @@
+//! Day 4: Camp Cleanup. [`Day04`] parses the assignments into one [`Pair`] of section ranges per
+//! line, and counts the pairs where one range contains the other in part 1, and the pairs that
+//! overlap at all in part 2.
+#![warn(missing_docs)]
+
+use anyhow::Result;
+use aoc_common::input::parse_lines;
+use aoc_common::parse::{parse_token, split_once, ParseError};
+use aoc_common::solution::Solution;
+
+/// The sections an Elf is assigned, both ends included.
+#[derive(Debug)]
+pub struct Range {
+    /// The first section.
+    pub from: u32,
+    /// The last section.
+    pub to: u32,
+}
+
+impl std::str::FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = split_once(s, s, "-")?;
+        Ok(Range {
+            from: parse_token(s, from)?,
+            to: parse_token(s, to)?,
+        })
+    }
+}
+
+impl Range {
+    fn contains(&self, other: &Range) -> bool {
+        self.from <= other.from && self.to >= other.to
+    }
+
+    fn overlaps_with(&self, other: &Range) -> bool {
+        (self.from <= other.from && self.to >= other.from)
+            || (other.from <= self.from && other.to >= self.from)
+    }
+}
+
+/// The assignments of a pair of Elves.
+pub struct Pair {
+    /// The first Elf's sections.
+    pub left: Range,
+    /// The second Elf's sections.
+    pub right: Range,
+}
+
+impl std::str::FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = split_once(s, s, ",")?;
+        Ok(Pair {
+            left: parse_token(s, left)?,
+            right: parse_token(s, right)?,
+        })
+    }
+}
+
+impl Pair {
+    fn fully_overlaps(&self) -> bool {
+        self.left.contains(&self.right) || self.right.contains(&self.left)
+    }
+
+    fn partially_overlaps(&self) -> bool {
+        self.left.overlaps_with(&self.right)
+    }
+}
+
+/// Finds redundant section assignments.
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Pair>> {
+        parse_lines(input)
+    }
+
+    fn part1(pairs: &Vec<Pair>, _: &()) -> Result<usize> {
+        Ok(pairs.iter().filter(|p| p.fully_overlaps()).count())
+    }
+
+    fn part2(pairs: &Vec<Pair>, _: &()) -> Result<usize> {
+        Ok(pairs.iter().filter(|p| p.partially_overlaps()).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day04::parse(EXAMPLE)?;
+        assert_eq!(Day04::part1(&input, &())?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day04::parse(EXAMPLE)?;
+        assert_eq!(Day04::part2(&input, &())?, 4);
+        Ok(())
+    }
+
+    fn range() -> impl Strategy<Value = Range> {
+        (0..100u32, 0..100u32).prop_map(|(a, b)| Range {
+            from: a.min(b),
+            to: a.max(b),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn contains_implies_overlaps(a in range(), b in range()) {
+            if a.contains(&b) {
+                prop_assert!(a.overlaps_with(&b));
+                prop_assert!(b.overlaps_with(&a));
+            }
+        }
+
+        #[test]
+        fn overlaps_is_symmetric(a in range(), b in range()) {
+            prop_assert_eq!(a.overlaps_with(&b), b.overlaps_with(&a));
+        }
+
+        #[test]
+        fn overlaps_means_a_shared_section(a in range(), b in range()) {
+            let shared = (a.from..=a.to).any(|s| (b.from..=b.to).contains(&s));
+            prop_assert_eq!(a.overlaps_with(&b), shared);
+        }
+    }
+}
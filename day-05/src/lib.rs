@@ -0,0 +1,285 @@
+//! Day 5: Supply Stacks. [`Day05`] parses the drawing and the moves into a [`Procedure`], and
+//! reads the top crates after the crane moves crates one at a time in part 1, and several at once
+//! in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::parse::{parse_token, ParseError, Words};
+use aoc_common::solution::{Part, Solution};
+use aoc_common::visual::{Frame, Frames, Glyph, Rgb, Visualize};
+use std::collections::VecDeque;
+use tracing::trace;
+
+/// A step of the rearrangement procedure.
+pub struct Move {
+    n: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    /// How many crates move.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The stack they leave, numbered from 1.
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// The stack they land on, numbered from 1.
+    pub fn to(&self) -> usize {
+        self.to
+    }
+}
+
+impl std::str::FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Words::new(s);
+
+        words.expect("move")?;
+        let n = words.parse("a number of crates")?;
+        words.expect("from")?;
+        let from = words.parse("a stack")?;
+        words.expect("to")?;
+        let to = words.parse("a stack")?;
+        words.end()?;
+
+        Ok(Move { n, from, to })
+    }
+}
+
+/// The starting stacks, top crate first, and the rearrangement procedure.
+pub struct Procedure {
+    stacks: Vec<VecDeque<char>>,
+    moves: Vec<Move>,
+}
+
+impl Procedure {
+    /// The stacks before the first move, each with its top crate first.
+    pub fn stacks(&self) -> &[VecDeque<char>] {
+        &self.stacks
+    }
+
+    /// The moves, which only name stacks that exist and never take more crates than are left on a
+    /// stack.
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+}
+
+fn parse_stacks(drawing: &str) -> Result<Vec<VecDeque<char>>> {
+    let mut lines: Vec<&str> = drawing.lines().collect();
+    let count = lines
+        .pop()
+        .ok_or(anyhow!("missing stacks drawing"))?
+        .split_ascii_whitespace()
+        .count();
+    if count == 0 {
+        return Err(anyhow!("no stacks in the drawing"));
+    }
+
+    let mut stacks = vec![VecDeque::new(); count];
+    for line in lines {
+        let crates = line.chars().skip(1).step_by(4);
+        for (stack, c) in stacks.iter_mut().zip(crates) {
+            if c.is_ascii_alphabetic() {
+                stack.push_back(c);
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+/// Rearranges the stacks of crates with the giant cargo crane.
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Procedure;
+    type Config = ();
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Procedure> {
+        let (drawing, moves) = input
+            .split_once("\n\n")
+            .ok_or(anyhow!("missing blank line between stacks and moves"))?;
+        let stacks = parse_stacks(drawing)?;
+        // Both cranes move as many crates between the same stacks, so the stacks keep the same
+        // heights whichever one does the moving.
+        let mut heights: Vec<usize> = stacks.iter().map(|s| s.len()).collect();
+        let mut parsed = vec![];
+        for l in moves.lines().filter(|l| !l.is_empty()) {
+            let m: Move = parse_token(input, l)?;
+            if let Some(s) = [m.from, m.to]
+                .into_iter()
+                .find(|s| !(1..=stacks.len()).contains(s))
+            {
+                return Err(ParseError::new(input, l, format!("no stack {}", s)).into());
+            }
+            if m.n > heights[m.from - 1] {
+                let message = format!(
+                    "only {} crates left on stack {}",
+                    heights[m.from - 1],
+                    m.from
+                );
+                return Err(ParseError::new(input, l, message).into());
+            }
+            heights[m.from - 1] -= m.n;
+            heights[m.to - 1] += m.n;
+            parsed.push(m);
+        }
+        Ok(Procedure {
+            stacks,
+            moves: parsed,
+        })
+    }
+
+    fn part1(procedure: &Procedure, _: &()) -> Result<String> {
+        let mut stacks_9000 = procedure.stacks.clone();
+        for m in &procedure.moves {
+            move_9000(&mut stacks_9000, m);
+        }
+        Ok(stacks_9000.iter().filter_map(|s| s.front()).collect())
+    }
+
+    fn part2(procedure: &Procedure, _: &()) -> Result<String> {
+        let mut stacks_9001 = procedure.stacks.clone();
+        for m in &procedure.moves {
+            move_9001(&mut stacks_9001, m);
+        }
+        Ok(stacks_9001.iter().filter_map(|s| s.front()).collect())
+    }
+}
+
+/// Watch the crane rearrange the stacks one move at a time, with the crates it just moved in red.
+impl Visualize for Day05 {
+    fn visualize(procedure: &Procedure, _: &(), part: Part, frames: Frames) -> Result<()> {
+        let crane = match part {
+            Part::One => move_9000,
+            Part::Two => move_9001,
+        };
+        let mut stacks = procedure.stacks.clone();
+        // Tall enough for every crate to end up on the same stack.
+        let height = stacks.iter().map(|s| s.len()).sum();
+        frames(&frame(&stacks, height, None), "the starting stacks")?;
+        for (i, m) in procedure.moves.iter().enumerate() {
+            crane(&mut stacks, m);
+            let caption = format!(
+                "move {} of {}: {} from {} to {}",
+                i + 1,
+                procedure.moves.len(),
+                m.n,
+                m.from,
+                m.to
+            );
+            frames(&frame(&stacks, height, Some(m)), &caption)?;
+        }
+        Ok(())
+    }
+}
+
+fn move_9000(stacks: &mut [VecDeque<char>], m: &Move) {
+    trace!(
+        n = m.n,
+        from = m.from,
+        to = m.to,
+        "moving crates one at a time"
+    );
+    for _ in 0..m.n {
+        let x = stacks[m.from - 1].pop_front().unwrap();
+        stacks[m.to - 1].push_front(x);
+    }
+}
+
+fn move_9001(stacks: &mut [VecDeque<char>], m: &Move) {
+    trace!(n = m.n, from = m.from, to = m.to, "moving crates at once");
+    let xs: Vec<char> = stacks[m.from - 1].drain(0..m.n).rev().collect();
+    for x in xs {
+        stacks[m.to - 1].push_front(x);
+    }
+}
+
+// The stacks drawn as in the puzzle, `height` crates high above their numbers, with the crates
+// that `moved` last in red.
+fn frame(stacks: &[VecDeque<char>], height: usize, moved: Option<&Move>) -> Frame {
+    let mut frame = Frame::new(stacks.len() * 4 - 1, height + 1, Glyph::BLANK);
+    for (i, stack) in stacks.iter().enumerate() {
+        let x = i * 4;
+        for (depth, c) in stack.iter().enumerate() {
+            let colour = match moved {
+                Some(m) if m.to == i + 1 && depth < m.n => Rgb::RED,
+                _ => Rgb::BROWN,
+            };
+            let y = height - stack.len() + depth;
+            for (dx, ch) in ['[', *c, ']'].into_iter().enumerate() {
+                frame[(x + dx, y)] = Glyph::new(ch, colour);
+            }
+        }
+        let number = (i + 1).to_string();
+        for (dx, ch) in number.chars().enumerate() {
+            frame[(x + 1 + dx, height)] = Glyph::new(ch, Rgb::GREY);
+        }
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day05::parse(EXAMPLE)?;
+        assert_eq!(Day05::part1(&input, &())?, "CMZ");
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day05::parse(EXAMPLE)?;
+        assert_eq!(Day05::part2(&input, &())?, "MCD");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_crates() {
+        let input = EXAMPLE.replace("move 1 from 1 to 2", "move 3 from 1 to 2");
+        let Err(e) = Day05::parse(&input) else {
+            panic!("the moves parsed");
+        };
+        assert!(
+            e.to_string().contains("only 2 crates left on stack 1"),
+            "{}",
+            e
+        );
+        assert!(Day05::parse("\n\nmove 1 from 1 to 1\n").is_err());
+    }
+
+    #[test]
+    fn test_visualize() -> Result<()> {
+        let input = Day05::parse(EXAMPLE)?;
+        let mut last = String::new();
+        Day05::visualize(&input, &(), Part::Two, &mut |frame, _| {
+            last = frame.to_string();
+            Ok(())
+        })?;
+        let expected = [
+            "           ",
+            "           ",
+            "        [D]",
+            "        [N]",
+            "        [Z]",
+            "[M] [C] [P]",
+            " 1   2   3 ",
+        ];
+        assert_eq!(last.lines().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+}
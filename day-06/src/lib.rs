@@ -0,0 +1,132 @@
+//! Day 6: Tuning Trouble. [`Day06`] keeps the datastream as it is and finds the end of the first
+//! start-of-packet marker in part 1, and of the first start-of-message marker in part 2, with
+//! [`find_marker`].
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::solution::Solution;
+use std::collections::{HashSet, VecDeque};
+
+struct Window<T> {
+    size: usize,
+    value: VecDeque<T>,
+}
+
+impl<T: Eq + std::hash::Hash> Window<T> {
+    fn new(size: usize) -> Window<T> {
+        Window {
+            size,
+            value: VecDeque::new(),
+        }
+    }
+
+    fn push(&mut self, v: T) {
+        self.value.push_back(v);
+        if self.value.len() > self.size {
+            self.value.pop_front();
+        }
+    }
+
+    fn value_set(&self) -> HashSet<&T> {
+        self.value.iter().collect()
+    }
+}
+
+/// Locks on to the communication device's signal.
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String, _: &()) -> Result<usize> {
+        find_marker(input.chars(), 4).ok_or(anyhow!("no marker found"))
+    }
+
+    fn part2(input: &String, _: &()) -> Result<usize> {
+        find_marker(input.chars(), 14).ok_or(anyhow!("no marker found"))
+    }
+}
+
+/// How many characters are read up to the end of the first `size` different characters in a row,
+/// if there are any.
+pub fn find_marker<I: IntoIterator<Item = char>>(chars: I, size: usize) -> Option<usize> {
+    let mut window = Window::new(size);
+    for (c, i) in chars.into_iter().zip(1..) {
+        window.push(c);
+        if window.value_set().len() >= size {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day06::parse(EXAMPLE)?;
+        assert_eq!(Day06::part1(&input, &())?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day06::parse(EXAMPLE)?;
+        assert_eq!(Day06::part2(&input, &())?, 19);
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_marker() {
+        for (buffer, window_size, marker_pos) in vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4, 7),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 4, 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 4, 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4, 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4, 11),
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 14, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 14, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14, 26),
+        ] {
+            assert_eq!(find_marker(buffer.chars(), window_size), Some(marker_pos));
+        }
+    }
+
+    // The end of the first `size` characters in a row that are all different.
+    fn brute_force_marker(buffer: &str, size: usize) -> Option<usize> {
+        let chars: Vec<char> = buffer.chars().collect();
+        (size..=chars.len()).find(|&end| {
+            let window = &chars[end - size..end];
+            window
+                .iter()
+                .enumerate()
+                .all(|(i, c)| !window[i + 1..].contains(c))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn find_marker_agrees_with_brute_force(buffer in "[a-f]{0,40}", size in 1..8usize) {
+            prop_assert_eq!(
+                find_marker(buffer.chars(), size),
+                brute_force_marker(&buffer, size)
+            );
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Day 7: No Space Left On Device. [`Day07`] replays the terminal transcript into the total size
+//! of every directory, keyed by its absolute path. Part 1 sums the small directories and part 2
+//! finds the smallest one whose deletion frees enough space for the update.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::parse::{parse_token, ParseError, Words};
+use aoc_common::solution::Solution;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The sizes that matter on the device.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Directories up to this size count towards part 1.
+    pub small_dir_size: u32,
+    /// Total space on the disk.
+    pub disk_size: u32,
+    /// Free space the update needs.
+    pub update_size: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            small_dir_size: 100000,
+            disk_size: 70000000,
+            update_size: 30000000,
+        }
+    }
+}
+
+/// Finds a directory to delete to make room for the system update.
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = HashMap<String, u32>;
+    type Config = Config;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<HashMap<String, u32>> {
+        parse_sizes(input)
+    }
+
+    fn part1(dirs: &HashMap<String, u32>, config: &Config) -> Result<u32> {
+        Ok(dirs
+            .iter()
+            .filter(|(_, s)| s <= &&config.small_dir_size)
+            .map(|(_, s)| s)
+            .sum())
+    }
+
+    fn part2(dirs: &HashMap<String, u32>, config: &Config) -> Result<u32> {
+        let used_space = dirs.get("/").ok_or(anyhow!("no root directory"))?;
+        let unused_space = config
+            .disk_size
+            .checked_sub(*used_space)
+            .ok_or(anyhow!("more space used than the disk size"))?;
+        let needed_space = config.update_size.saturating_sub(unused_space);
+        dirs.iter()
+            .filter(|(_, s)| s >= &&needed_space)
+            .map(|(_, s)| *s)
+            .min()
+            .ok_or(anyhow!("no directory is big enough"))
+    }
+}
+
+/// The total size of every directory that the terminal `output` visits, keyed by its absolute
+/// path.
+pub fn parse_sizes(output: &str) -> Result<HashMap<String, u32>> {
+    let mut path = PathBuf::new();
+    let mut sizes: HashMap<String, u32> = HashMap::new();
+
+    for l in output.lines().filter(|l| !l.is_empty()) {
+        read_line(l, &mut path, &mut sizes).map_err(|e| ParseError::locate(e, output, l))?;
+    }
+    // The transcript may end anywhere in the tree: add the sizes it was in to those above.
+    while path.parent().is_some() {
+        leave_dir(&mut path, &mut sizes)?;
+    }
+
+    Ok(sizes)
+}
+
+fn read_line(l: &str, path: &mut PathBuf, sizes: &mut HashMap<String, u32>) -> Result<()> {
+    let mut words = Words::new(l);
+    match words.word("a command or a listing")? {
+        "$" => match words.word("a command")? {
+            "cd" => match words.word("a directory")? {
+                ".." => leave_dir(path, sizes)?,
+                dir => path.push(dir),
+            },
+            "ls" => {}
+            c => bail!(ParseError::new(l, c, "unrecognised command")),
+        },
+        "dir" => {
+            words.word("a directory")?;
+        }
+        size => {
+            let size: u32 = parse_token(l, size)?;
+            words.word("a file name")?;
+            add_size(path, sizes, size)?;
+        }
+    }
+    Ok(words.end()?)
+}
+
+// Adds the size of the current directory to its parent's and moves to the parent.
+fn leave_dir(path: &mut PathBuf, sizes: &mut HashMap<String, u32>) -> Result<()> {
+    let dir_size = *sizes.get(path.to_str().unwrap()).unwrap_or(&0);
+    path.pop();
+    add_size(path, sizes, dir_size)
+}
+
+fn add_size(path: &Path, sizes: &mut HashMap<String, u32>, size: u32) -> Result<()> {
+    let total = sizes.entry(path.to_str().unwrap().to_string()).or_insert(0);
+    *total = total
+        .checked_add(size)
+        .ok_or(anyhow!("{} is too large", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day07::parse(EXAMPLE)?;
+        assert_eq!(Day07::part1(&input, &Config::default())?, 95437);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day07::parse(EXAMPLE)?;
+        assert_eq!(Day07::part2(&input, &Config::default())?, 24933642);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_sizes() -> Result<()> {
+        let dirs = parse_sizes(EXAMPLE)?;
+        assert_eq!(
+            dirs,
+            HashMap::from([
+                ("/a/e".to_string(), 584),
+                ("/a".to_string(), 94853),
+                ("/d".to_string(), 24933642),
+                ("/".to_string(), 48381165),
+            ])
+        );
+        assert_eq!(
+            parse_sizes("$ cd /\n$ cd a\n$ cd b\n$ ls\n5 c.txt\n")?,
+            HashMap::from([
+                ("/a/b".to_string(), 5),
+                ("/a".to_string(), 5),
+                ("/".to_string(), 5),
+            ])
+        );
+        assert_eq!(
+            parse_sizes("$ cd /\n$ ls\n12x b.txt\n")
+                .unwrap_err()
+                .to_string(),
+            "input:3:1: invalid digit found in string"
+        );
+        Ok(())
+    }
+}
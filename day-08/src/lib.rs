@@ -0,0 +1,127 @@
+//! Day 8: Treetop Tree House. [`Day08`] parses the map into a grid of tree heights, and counts the
+//! trees visible from outside the grid in part 1, and finds the best scenic score in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::direction::Direction4;
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::solution::Solution;
+
+/// Scouts the forest for a tree house spot.
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<u32>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u32>> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(trees: &Grid<u32>, _: &()) -> Result<usize> {
+        Ok(trees.positions().filter(|p| is_visible(trees, *p)).count())
+    }
+
+    fn part2(trees: &Grid<u32>, _: &()) -> Result<usize> {
+        trees
+            .positions()
+            .map(|p| scenic_score(trees, p))
+            .max()
+            .ok_or(anyhow!("empty grid"))
+    }
+}
+
+fn scenic_score(trees: &Grid<u32>, p: Pos) -> usize {
+    Direction4::ALL
+        .iter()
+        .map(|d| viewing_distance(trees, p, *d))
+        .product()
+}
+
+fn viewing_distance(trees: &Grid<u32>, p: Pos, direction: Direction4) -> usize {
+    let te = to_edge(trees, p, direction);
+    te.iter()
+        .position(|h| h >= &trees[p])
+        .map(|p| p + 1)
+        .unwrap_or(te.len())
+}
+
+fn is_visible(trees: &Grid<u32>, p: Pos) -> bool {
+    Direction4::ALL
+        .iter()
+        .any(|d| trees.ray(p, d.unit()).all(|(_, h)| h < &trees[p]))
+}
+
+fn to_edge(trees: &Grid<u32>, p: Pos, direction: Direction4) -> Vec<u32> {
+    trees.ray(p, direction.unit()).map(|(_, h)| *h).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day08::parse(EXAMPLE)?;
+        assert_eq!(Day08::part1(&input, &())?, 21);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day08::parse(EXAMPLE)?;
+        assert_eq!(Day08::part2(&input, &())?, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test() -> Result<()> {
+        let trees = Day08::parse(EXAMPLE)?;
+
+        assert_eq!(to_edge(&trees, (2, 2), Direction4::Up), vec![5, 3]);
+        assert_eq!(to_edge(&trees, (2, 2), Direction4::Down), vec![5, 3]);
+        assert_eq!(to_edge(&trees, (2, 2), Direction4::Right), vec![3, 2]);
+        assert_eq!(to_edge(&trees, (2, 2), Direction4::Left), vec![5, 6]);
+
+        assert!(is_visible(&trees, (0, 0)));
+        assert!(is_visible(&trees, (1, 0)));
+        assert!(is_visible(&trees, (2, 0)));
+        assert!(is_visible(&trees, (3, 0)));
+        assert!(is_visible(&trees, (4, 0)));
+        assert!(is_visible(&trees, (0, 1)));
+        assert!(is_visible(&trees, (1, 1)));
+        assert!(is_visible(&trees, (2, 1)));
+        assert!(!is_visible(&trees, (3, 1)));
+        assert!(is_visible(&trees, (4, 1)));
+        assert!(is_visible(&trees, (0, 2)));
+        assert!(is_visible(&trees, (1, 2)));
+        assert!(!is_visible(&trees, (2, 2)));
+        assert!(is_visible(&trees, (3, 2)));
+        assert!(is_visible(&trees, (4, 2)));
+        assert!(is_visible(&trees, (0, 3)));
+        assert!(!is_visible(&trees, (1, 3)));
+        assert!(is_visible(&trees, (2, 3)));
+        assert!(!is_visible(&trees, (3, 3)));
+        assert!(is_visible(&trees, (4, 3)));
+        assert!(is_visible(&trees, (0, 4)));
+        assert!(is_visible(&trees, (1, 4)));
+        assert!(is_visible(&trees, (2, 4)));
+        assert!(is_visible(&trees, (3, 4)));
+        assert!(is_visible(&trees, (4, 4)));
+
+        assert_eq!(viewing_distance(&trees, (2, 1), Direction4::Up), 1);
+        assert_eq!(viewing_distance(&trees, (2, 1), Direction4::Left), 1);
+        assert_eq!(viewing_distance(&trees, (2, 1), Direction4::Right), 2);
+        assert_eq!(viewing_distance(&trees, (2, 1), Direction4::Down), 2);
+
+        assert_eq!(scenic_score(&trees, (2, 1)), 4);
+        assert_eq!(scenic_score(&trees, (2, 3)), 8);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,309 @@
+//! Day 9: Rope Bridge. [`Day09`] parses the motions of the rope's head, and counts the positions
+//! the tail visits with two knots in part 1 and ten in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{bail, Result};
+use aoc_common::direction::Direction4;
+use aoc_common::grid::Pos;
+use aoc_common::input::parse_lines;
+use aoc_common::parse::{ParseError, Words};
+use aoc_common::point::Point2;
+use aoc_common::solution::{Part, Solution};
+use aoc_common::visual::{Frame, Frames, Glyph, Rgb, Visualize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashSet;
+use tracing::trace;
+
+fn parse_direction(line: &str, s: &str) -> Result<Direction4, ParseError> {
+    match s {
+        "U" => Ok(Direction4::Up),
+        "D" => Ok(Direction4::Down),
+        "L" => Ok(Direction4::Left),
+        "R" => Ok(Direction4::Right),
+        _ => Err(ParseError::new(line, s, "expected U, D, L or R")),
+    }
+}
+
+/// A motion of the head, one step at a time.
+#[derive(Debug, Clone)]
+pub struct Move {
+    /// Which way the head goes.
+    pub direction: Direction4,
+    /// How many steps it takes.
+    pub length: u32,
+}
+
+impl std::str::FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Words::new(s);
+
+        let direction = parse_direction(s, words.word("a direction")?)?;
+        let length = words.parse("a number of steps")?;
+        words.end()?;
+
+        Ok(Move { direction, length })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Rope {
+    knots: Vec<Point2>,
+}
+
+impl Rope {
+    fn at_origin(knots: usize) -> Result<Rope> {
+        if knots == 0 {
+            bail!("a rope needs at least one knot");
+        }
+        Ok(Rope {
+            knots: vec![Point2::ORIGIN; knots],
+        })
+    }
+
+    #[cfg(test)]
+    fn new<const N: usize>(knots: [(i64, i64); N]) -> Rope {
+        Rope {
+            knots: knots.into_iter().map(Point2::from).collect(),
+        }
+    }
+
+    fn tail(&self) -> Point2 {
+        *self.knots.last().unwrap()
+    }
+
+    fn mv(&self, direction: Direction4) -> Rope {
+        let new_head = self.knots[0] + direction.unit();
+        let mut new_knots = vec![new_head];
+        for knot in self.knots.iter().skip(1) {
+            new_knots.push(Self::mv_knot(*knot, *new_knots.last().unwrap()))
+        }
+
+        Rope { knots: new_knots }
+    }
+
+    /// Moves `knot` one step towards `head`, unless they are already touching.
+    fn mv_knot(knot: Point2, head: Point2) -> Point2 {
+        if knot.chebyshev(head) > 1 {
+            knot + (head - knot).signum()
+        } else {
+            knot
+        }
+    }
+}
+
+/// The lengths of the ropes.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Knots in the rope of part 1, head and tail included. From 1 to 1000.
+    #[serde(deserialize_with = "knots")]
+    pub knots_part1: usize,
+    /// Knots in the rope of part 2. From 1 to 1000.
+    #[serde(deserialize_with = "knots")]
+    pub knots_part2: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            knots_part1: 2,
+            knots_part2: 10,
+        }
+    }
+}
+
+// Every knot follows every step of the head, so a rope much longer than the puzzle's would only
+// take up time and memory.
+const MAX_KNOTS: usize = 1_000;
+
+fn knots<'de, D: Deserializer<'de>>(d: D) -> Result<usize, D::Error> {
+    let knots = usize::deserialize(d)?;
+    if !(1..=MAX_KNOTS).contains(&knots) {
+        return Err(D::Error::custom(format!(
+            "a rope of {} knots, but it takes from 1 to {}",
+            knots, MAX_KNOTS
+        )));
+    }
+    Ok(knots)
+}
+
+/// Follows the tail of a rope dragged around the plane.
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Move>;
+    type Config = Config;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Move>> {
+        parse_lines(input)
+    }
+
+    fn part1(moves: &Vec<Move>, config: &Config) -> Result<usize> {
+        Ok(count_tail_positions(
+            Rope::at_origin(config.knots_part1)?,
+            moves.clone(),
+        ))
+    }
+
+    fn part2(moves: &Vec<Move>, config: &Config) -> Result<usize> {
+        Ok(count_tail_positions(
+            Rope::at_origin(config.knots_part2)?,
+            moves.clone(),
+        ))
+    }
+}
+
+/// Watch the rope after each move, over the positions its tail has visited.
+impl Visualize for Day09 {
+    fn visualize(moves: &Vec<Move>, config: &Config, part: Part, frames: Frames) -> Result<()> {
+        let knots = match part {
+            Part::One => config.knots_part1,
+            Part::Two => config.knots_part2,
+        };
+        let mut rope = Rope::at_origin(knots)?;
+
+        // The knots never leave the box the head moves in.
+        let (mut min, mut max, mut head) = (Point2::ORIGIN, Point2::ORIGIN, Point2::ORIGIN);
+        for m in moves {
+            for _ in 0..m.length {
+                head += m.direction.unit();
+                min = Point2::new(min.x.min(head.x), min.y.min(head.y));
+                max = Point2::new(max.x.max(head.x), max.y.max(head.y));
+            }
+        }
+        let size = max - min + Point2::new(1, 1);
+        let pos = |p: Point2| Pos::try_from(p - min).unwrap();
+
+        let mut visited = Frame::new(size.x as usize, size.y as usize, Glyph::BLANK);
+        visited[pos(Point2::ORIGIN)] = Glyph::new('s', Rgb::WHITE);
+        for (i, m) in moves.iter().enumerate() {
+            for _ in 0..m.length {
+                rope = rope.mv(m.direction);
+                visited[pos(rope.tail())] = Glyph::new('#', Rgb::GREY);
+            }
+            let mut frame = visited.clone();
+            for (i, knot) in rope.knots.iter().enumerate().rev() {
+                frame[pos(*knot)] = match i {
+                    0 => Glyph::new('H', Rgb::RED),
+                    _ => Glyph::new(char::from_digit(i as u32 % 10, 10).unwrap(), Rgb::YELLOW),
+                };
+            }
+            let caption = format!(
+                "move {} of {}: {:?} {}",
+                i + 1,
+                moves.len(),
+                m.direction,
+                m.length
+            );
+            frames(&frame, &caption)?;
+        }
+        Ok(())
+    }
+}
+
+fn count_tail_positions<I: IntoIterator<Item = Move>>(start: Rope, moves: I) -> usize {
+    let mut rope = start;
+    let mut positions: HashSet<Point2> = HashSet::from([rope.tail()]);
+    for m in moves {
+        for _ in 0..m.length {
+            rope = rope.mv(m.direction);
+            positions.insert(rope.tail());
+        }
+        trace!(direction = ?m.direction, length = m.length, tail = ?rope.tail(), "moved");
+    }
+
+    positions.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use proptest::prelude::*;
+    use proptest::sample::select;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day09::parse(EXAMPLE)?;
+        assert_eq!(Day09::part1(&input, &Config::default())?, 13);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day09::parse(EXAMPLE)?;
+        assert_eq!(Day09::part2(&input, &Config::default())?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config() {
+        let config = |s: &str| aoc_common::config::parse::<Config>(&s.parse().unwrap());
+        assert_eq!(config("knots_part1 = 1").unwrap().knots_part1, 1);
+        assert_eq!(config("knots_part2 = 1000").unwrap().knots_part2, 1000);
+        assert!(config("knots_part1 = 0").is_err());
+        assert!(config("knots_part2 = 1001").is_err());
+        assert!(config("knots_part1 = 1000000000000").is_err());
+    }
+
+    #[test]
+    fn test_rope() {
+        assert_eq!(
+            Rope::new([(0, 0), (0, 0)]).mv(Direction4::Right),
+            Rope::new([(1, 0), (0, 0)])
+        );
+        assert_eq!(
+            Rope::new([(2, 1), (1, 1)]).mv(Direction4::Right),
+            Rope::new([(3, 1), (2, 1)])
+        );
+        assert_eq!(
+            Rope::new([(1, 2), (1, 1)]).mv(Direction4::Down),
+            Rope::new([(1, 3), (1, 2)])
+        );
+        assert_eq!(
+            Rope::new([(4, 0), (3, 0)]).mv(Direction4::Up),
+            Rope::new([(4, -1), (3, 0)])
+        );
+        assert_eq!(
+            Rope::new([(2, -2), (1, -1)]).mv(Direction4::Up),
+            Rope::new([(2, -3), (2, -2)])
+        );
+        assert_eq!(
+            Rope::new([(2, 2), (1, 1)]).mv(Direction4::Right),
+            Rope::new([(3, 2), (2, 2)])
+        );
+    }
+
+    #[test]
+    fn test_part2_larger_example() -> Result<()> {
+        let input = Day09::parse(include_str!("../example-2.txt"))?;
+        assert_eq!(Day09::part2(&input, &Config::default())?, 36);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn knots_stay_adjacent(
+            knots in 1..12usize,
+            moves in prop::collection::vec(select(Direction4::ALL.to_vec()), 0..100),
+        ) {
+            let mut rope = Rope::at_origin(knots).unwrap();
+            for d in moves {
+                let head = rope.knots[0];
+                rope = rope.mv(d);
+                prop_assert_eq!(rope.knots.len(), knots);
+                prop_assert_eq!(rope.knots[0], head + d.unit());
+                for pair in rope.knots.windows(2) {
+                    prop_assert!(pair[0].chebyshev(pair[1]) <= 1, "{:?}", rope);
+                }
+            }
+        }
+    }
+}
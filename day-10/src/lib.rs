@@ -0,0 +1,205 @@
+//! Day 10: Cathode-Ray Tube. [`Day10`] parses the CPU's program, and sums the signal strengths
+//! during some cycles in part 1, and draws the CRT screen, one line of `#` and `.` per row, in
+//! part 2.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::input::parse_lines;
+use aoc_common::parse::{ParseError, Words};
+use aoc_common::solution::{Part, Solution};
+use aoc_common::visual::{Frame, Frames, Glyph, Rgb, Visualize};
+use serde::Deserialize;
+use tracing::trace;
+
+/// An instruction of the CPU, which has a single register `X`.
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    /// Adds to `X` after two cycles.
+    AddX(i32),
+    /// Does nothing for a cycle.
+    Noop,
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Words::new(s);
+
+        let instr = words.word("an instruction")?;
+        let instruction = match instr {
+            "addx" => Instruction::AddX(words.parse("a number")?),
+            "noop" => Instruction::Noop,
+            _ => return Err(ParseError::new(s, instr, "unrecognised instruction")),
+        };
+        words.end()?;
+        Ok(instruction)
+    }
+}
+
+/// Where to sample the signal.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Cycles whose signal strength is summed in part 1.
+    pub cycles: Vec<usize>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            cycles: vec![20, 60, 100, 140, 180, 220],
+        }
+    }
+}
+
+/// Runs the handheld device's CPU and renders its screen.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Config = Config;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        parse_lines(input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>, config: &Config) -> Result<i32> {
+        let reg_values = registry_values(1, instructions.clone())?;
+        config.cycles.iter().try_fold(0i32, |sum, &c| {
+            let x = c
+                .checked_sub(1)
+                .and_then(|i| reg_values.get(i))
+                .ok_or(anyhow!("the program does not run cycle {}", c))?;
+            (c as i32)
+                .checked_mul(*x)
+                .and_then(|strength| sum.checked_add(strength))
+                .ok_or(anyhow!("the signal strengths add up past {}", i32::MAX))
+        })
+    }
+
+    fn part2(instructions: &Vec<Instruction>, _: &Config) -> Result<String> {
+        Ok(render(registry_values(1, instructions.clone())?))
+    }
+}
+
+/// Both parts run the same program: watch the CRT draw its screen, one pixel per cycle.
+impl Visualize for Day10 {
+    fn visualize(
+        instructions: &Vec<Instruction>,
+        _: &Config,
+        _: Part,
+        frames: Frames,
+    ) -> Result<()> {
+        let reg_values = registry_values(1, instructions.clone())?;
+        let mut screen = Frame::new(40, reg_values.len().div_ceil(40), Glyph::BLANK);
+        for (sprite_pos, cycle) in reg_values.into_iter().zip(0..) {
+            let pixel = (cycle as usize % 40, cycle as usize / 40);
+            screen[pixel] = Glyph::new('@', Rgb::YELLOW);
+            frames(&screen, &format!("cycle {}: X = {}", cycle + 1, sprite_pos))?;
+            screen[pixel] = if is_lit(sprite_pos, cycle) {
+                Glyph::new('#', Rgb::GREEN)
+            } else {
+                Glyph::new('.', Rgb::GREY)
+            };
+        }
+        frames(&screen, "the program has ended")
+    }
+}
+
+// Whether the CRT draws a lit pixel during `cycle`, counted from 0.
+fn is_lit(sprite_pos: i32, cycle: i32) -> bool {
+    let pixel = cycle % 40;
+    (sprite_pos as i64 - pixel as i64).abs() <= 1
+}
+
+fn render<I: IntoIterator<Item = i32>>(reg_values: I) -> String {
+    let mut screen = vec![];
+    for (sprite_pos, cycle) in reg_values.into_iter().zip(0..) {
+        if cycle % 40 == 0 {
+            screen.push('\n');
+        }
+        if is_lit(sprite_pos, cycle) {
+            screen.push('#')
+        } else {
+            screen.push('.')
+        }
+    }
+    screen.push('\n');
+    screen.iter().collect()
+}
+
+// The value of `X` during every cycle, unless it overflows.
+fn registry_values<I: IntoIterator<Item = Instruction>>(
+    init_value: i32,
+    instructions: I,
+) -> Result<Vec<i32>> {
+    let mut x = init_value;
+    let mut x_values = vec![];
+    for i in instructions {
+        trace!(cycle = x_values.len() + 1, x, instruction = ?i);
+        match i {
+            Instruction::AddX(n) => {
+                x_values.push(x);
+                x_values.push(x);
+                x = x
+                    .checked_add(n)
+                    .ok_or(anyhow!("X overflows after cycle {}", x_values.len()))?;
+            }
+            Instruction::Noop => x_values.push(x),
+        }
+    }
+    Ok(x_values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day10::parse(EXAMPLE)?;
+        assert_eq!(Day10::part1(&input, &Config::default())?, 13140);
+        Ok(())
+    }
+
+    #[test]
+    fn test_render() -> Result<()> {
+        let reg_values = registry_values(1, Day10::parse(EXAMPLE)?)?;
+
+        assert_eq!(reg_values[19], 21);
+        assert_eq!(reg_values[59], 19);
+        assert_eq!(reg_values[99], 18);
+        assert_eq!(reg_values[139], 21);
+        assert_eq!(reg_values[179], 16);
+        assert_eq!(reg_values[219], 18);
+
+        assert_eq!(
+            render(reg_values),
+            r#"
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() -> Result<()> {
+        let input = Day10::parse("addx 2147483647\naddx 2147483647\n")?;
+        let e = Day10::part1(&input, &Config::default()).unwrap_err();
+        assert_eq!(e.to_string(), "X overflows after cycle 2");
+        assert!(Day10::part2(&input, &Config::default()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,272 @@
+//! Day 11: Monkey in the Middle. [`Day11`] parses the notes into one [`Monkey`] each, and plays
+//! the rounds of keep away, multiplying how many items the two busiest monkeys inspect: 20 rounds
+//! with relief in part 1, and 10000 without in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::input::split_blocks;
+use aoc_common::parse::{parse_token, strip_prefix, ParseError, Words};
+use aoc_common::solution::Solution;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use tracing::debug;
+
+/// How a monkey changes the worry level of an item it inspects.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    /// Adds a number.
+    Add(u64),
+    /// Multiplies by a number.
+    Multiply(u64),
+    /// Multiplies by itself.
+    Square,
+}
+
+impl std::str::FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Words::new(s);
+        words.expect("new")?;
+        words.expect("=")?;
+        words.expect("old")?;
+        let operator = words.word("an operator")?;
+        let operand = words.word("an operand")?;
+        words.end()?;
+        match (operator, operand) {
+            ("*", "old") => Ok(Operation::Square),
+            ("*", n) => Ok(Operation::Multiply(parse_token(s, n)?)),
+            ("+", n) => Ok(Operation::Add(parse_token(s, n)?)),
+            _ => Err(ParseError::new(s, operator, "expected '*' or '+'")),
+        }
+    }
+}
+
+impl Operation {
+    // The new worry level, unless it overflows.
+    fn apply(&self, old: u64) -> Option<u64> {
+        match self {
+            Operation::Add(n) => old.checked_add(*n),
+            Operation::Multiply(n) => old.checked_mul(*n),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+/// A monkey holding items, and how it decides where to throw them.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test_divisible: u64,
+    test_if_true: usize,
+    test_if_false: usize,
+    inspections: u64,
+}
+
+impl Monkey {
+    /// The worry levels of the items it starts with, in the order it inspects them.
+    pub fn items(&self) -> &[u64] {
+        &self.items
+    }
+
+    /// How it changes worry levels.
+    pub fn operation(&self) -> &Operation {
+        &self.operation
+    }
+
+    /// It throws the items whose worry level this divides to one monkey, and the others to another.
+    pub fn divisor(&self) -> u64 {
+        self.test_divisible
+    }
+
+    /// The monkeys it throws to when the divisor divides the worry level, and when it does not,
+    /// numbered from 0.
+    pub fn targets(&self) -> (usize, usize) {
+        (self.test_if_true, self.test_if_false)
+    }
+}
+
+impl std::str::FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim);
+        let mut field = |prefix: &str| {
+            let l = lines.next().ok_or_else(|| {
+                ParseError::new(s, &s[s.len()..], format!("expected '{}'", prefix))
+            })?;
+            strip_prefix(s, l, prefix)
+        };
+
+        field("Monkey ")?;
+        let items = field("Starting items: ")?
+            .split(", ")
+            .map(|i| parse_token(s, i))
+            .collect::<Result<Vec<u64>, _>>()?;
+        let operation = parse_token(s, field("Operation: ")?)?;
+        let test_divisible = parse_token(s, field("Test: divisible by ")?)?;
+        let test_if_true = parse_token(s, field("If true: throw to monkey ")?)?;
+        let test_if_false = parse_token(s, field("If false: throw to monkey ")?)?;
+
+        Ok(Monkey {
+            items,
+            operation,
+            test_divisible,
+            test_if_true,
+            test_if_false,
+            inspections: 0,
+        })
+    }
+}
+
+/// How long the monkeys play, and how relieved you are.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Rounds played in part 1.
+    pub rounds_part1: usize,
+    /// Rounds played in part 2.
+    pub rounds_part2: usize,
+    /// Worry levels are divided by this after every inspection in part 1. At least 1.
+    #[serde(deserialize_with = "relief_factor")]
+    pub relief_factor: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            rounds_part1: 20,
+            rounds_part2: 10_000,
+            relief_factor: 3,
+        }
+    }
+}
+
+// Worry levels cannot be divided by 0.
+fn relief_factor<'de, D: Deserializer<'de>>(d: D) -> Result<u64, D::Error> {
+    let factor = u64::deserialize(d)?;
+    if factor == 0 {
+        return Err(D::Error::custom("relief_factor must be at least 1"));
+    }
+    Ok(factor)
+}
+
+/// Works out which monkeys to chase.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Config = Config;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>> {
+        let monkeys = split_blocks(input)
+            .map(|b| parse_token(input, b))
+            .collect::<Result<Vec<Monkey>, _>>()?;
+        if monkeys.len() < 2 {
+            bail!("it takes two monkeys to play keep away");
+        }
+        for (i, m) in monkeys.iter().enumerate() {
+            if m.test_divisible == 0 {
+                bail!("monkey {} tests whether worry levels are divisible by 0", i);
+            }
+            if let Some(t) = [m.test_if_true, m.test_if_false]
+                .into_iter()
+                .find(|t| *t >= monkeys.len())
+            {
+                bail!("monkey {} throws to monkey {}, which does not exist", i, t);
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Vec<Monkey>, config: &Config) -> Result<u64> {
+        monkey_business(monkeys, config.rounds_part1, Some(config.relief_factor))
+    }
+
+    fn part2(monkeys: &Vec<Monkey>, config: &Config) -> Result<u64> {
+        monkey_business(monkeys, config.rounds_part2, None)
+    }
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief_factor: Option<u64>) -> Result<u64> {
+    let mut ms = monkeys.to_vec();
+    let lcm = monkeys
+        .iter()
+        .try_fold(1u64, |lcm, m| lcm.checked_mul(m.test_divisible))
+        .ok_or(anyhow!("the divisors multiply past {}", u64::MAX))?;
+    let overflow = || anyhow!("worry levels grow past {}", u64::MAX);
+
+    for round in 1..=rounds {
+        for i in 0..ms.len() {
+            let monkey = ms[i].clone();
+
+            for item in &monkey.items {
+                ms[i].inspections += 1;
+
+                let mut worry_level = monkey.operation.apply(*item).ok_or_else(overflow)?;
+                if let Some(rf) = relief_factor {
+                    worry_level /= rf;
+                } else {
+                    worry_level %= lcm;
+                }
+
+                let next_monkey = if worry_level.is_multiple_of(monkey.test_divisible) {
+                    monkey.test_if_true
+                } else {
+                    monkey.test_if_false
+                };
+
+                ms[i].items = vec![];
+                ms[next_monkey].items.push(worry_level);
+            }
+        }
+        debug!(round, inspections = ?ms.iter().map(|m| m.inspections).collect::<Vec<_>>());
+    }
+
+    let mut inspections: Vec<u64> = ms.iter().map(|m| m.inspections).collect();
+    inspections.sort();
+    inspections.reverse();
+
+    inspections[0]
+        .checked_mul(inspections[1])
+        .ok_or(anyhow!("the monkey business grows past {}", u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day11::parse(EXAMPLE)?;
+        assert_eq!(Day11::part1(&input, &Config::default())?, 10605);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day11::parse(EXAMPLE)?;
+        assert_eq!(Day11::part2(&input, &Config::default())?, 2713310158);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config() {
+        let config = |s: &str| aoc_common::config::parse::<Config>(&s.parse().unwrap());
+        assert_eq!(config("relief_factor = 1").unwrap().relief_factor, 1);
+        assert!(config("relief_factor = 0").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_unplayable_games() {
+        let (first, _) = EXAMPLE.split_once("\n\n").unwrap();
+        assert!(Day11::parse(first).is_err());
+        assert!(Day11::parse(&EXAMPLE.replace("divisible by 23", "divisible by 0")).is_err());
+    }
+}
@@ -0,0 +1,150 @@
+//! Day 12: Hill Climbing Algorithm. [`Day12`] parses the heightmap into a grid of letters, and
+//! finds the fewest steps up to the best signal `E` from the start `S` in part 1, and from any
+//! square at the lowest elevation in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::search::{astar_observed, bfs_observed, Graph, Observer, Path};
+use aoc_common::solution::{Part, Solution};
+use aoc_common::visual::{Frames, Glyph, Rgb, Visualize};
+use std::collections::HashSet;
+
+/// Finds the shortest hike to the spot with the best signal.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>> {
+        Grid::parse_chars(input)
+    }
+
+    fn part1(grid: &Grid<char>, _: &()) -> Result<usize> {
+        Ok(hike(grid, Part::One, &mut |_, _| Ok(()))?.nodes.len() - 1)
+    }
+
+    fn part2(grid: &Grid<char>, _: &()) -> Result<usize> {
+        Ok(hike(grid, Part::Two, &mut |_, _| Ok(()))?.nodes.len() - 1)
+    }
+}
+
+/// Watch the search spread over the heightmap, shaded from low to high, with its open squares in
+/// yellow and the ones it is done with in green, then the hike it found, one step at a time.
+impl Visualize for Day12 {
+    fn visualize(grid: &Grid<char>, _: &(), part: Part, frames: Frames) -> Result<()> {
+        let mut frame = grid.map(|c| {
+            let t = height(*c).saturating_sub(height('a')) as f64 / 25.0;
+            let t = t.min(1.0);
+            Glyph::new(*c, Rgb::BLUE.mix(Rgb::WHITE, t))
+        });
+        let mut open = HashSet::new();
+        let mut closed = 0;
+        let path = hike(grid, part, &mut |p, opened| {
+            open.remove(p);
+            closed += 1;
+            frame[*p] = Glyph::new(frame[*p].ch, Rgb::GREEN);
+            for n in opened {
+                open.insert(*n);
+                frame[*n] = Glyph::new(frame[*n].ch, Rgb::YELLOW);
+            }
+            let caption = format!("{} squares open, {} done", open.len(), closed);
+            frames(&frame, &caption)
+        })?;
+
+        let steps = path.nodes.len() - 1;
+        for (i, p) in path.nodes.into_iter().enumerate() {
+            frame[p] = Glyph::new(frame[p].ch, Rgb::RED);
+            frames(&frame, &format!("step {} of {}", i, steps))?;
+        }
+        Ok(())
+    }
+}
+
+// The shortest path to `E`, from `S` in part 1 or from any lowest square in part 2, telling
+// `observe` about each square the search expands.
+fn hike(grid: &Grid<char>, part: Part, observe: Observer<Pos>) -> Result<Path<Pos>> {
+    match part {
+        Part::One => {
+            let (start, end) = find_start_end(grid)?;
+            let hill = Hill { grid, end };
+            astar_observed(&hill, [start], |p| *p == end, observe)?.ok_or(anyhow!("no path found"))
+        }
+        Part::Two => {
+            let (starts, end) = find_starts_end(grid)?;
+            let hill = Hill { grid, end };
+            bfs_observed(&hill, starts, |p| *p == end, observe)?.ok_or(anyhow!("no path"))
+        }
+    }
+}
+
+// The heightmap, where each step goes up at most one level, heading for `end`.
+struct Hill<'a> {
+    grid: &'a Grid<char>,
+    end: Pos,
+}
+
+impl Graph for Hill<'_> {
+    type Node = Pos;
+
+    fn neighbours(&self, p: &Pos) -> impl IntoIterator<Item = (Pos, u64)> {
+        let from = height(self.grid[*p]);
+        self.grid
+            .neighbours4(*p)
+            .filter(move |n| height(self.grid[*n]) <= from + 1)
+            .map(|n| (n, 1))
+    }
+
+    fn heuristic(&self, &(x, y): &Pos) -> u64 {
+        (x.abs_diff(self.end.0) + y.abs_diff(self.end.1)) as u64
+    }
+}
+
+fn height(c: char) -> u32 {
+    match c {
+        'S' => 'a' as u32,
+        'E' => 'z' as u32,
+        c => c as u32,
+    }
+}
+
+fn find_start_end(grid: &Grid<char>) -> Result<(Pos, Pos)> {
+    let start = grid.position(|c| *c == 'S').ok_or(anyhow!("no start"))?;
+    let end = grid.position(|c| *c == 'E').ok_or(anyhow!("no end"))?;
+    Ok((start, end))
+}
+
+fn find_starts_end(grid: &Grid<char>) -> Result<(Vec<Pos>, Pos)> {
+    let starts = grid
+        .iter()
+        .filter(|(_, c)| **c == 'S' || **c == 'a')
+        .map(|(p, _)| p)
+        .collect();
+    let end = grid.position(|c| *c == 'E').ok_or(anyhow!("no end"))?;
+    Ok((starts, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day12::parse(EXAMPLE)?;
+        assert_eq!(Day12::part1(&input, &())?, 31);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day12::parse(EXAMPLE)?;
+        assert_eq!(Day12::part2(&input, &())?, 29);
+        Ok(())
+    }
+}
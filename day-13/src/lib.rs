@@ -0,0 +1,264 @@
+//! Day 13: Distress Signal. [`Day13`] parses the packets into pairs of [`Value`]s, which are
+//! ordered the way the puzzle compares packets. Part 1 sums the indices of the pairs in the right
+//! order and part 2 locates the divider packets among all the packets sorted.
+#![warn(missing_docs)]
+
+use anyhow::Result;
+use aoc_common::input::split_blocks;
+use aoc_common::parse::{parse_token, ParseError};
+use aoc_common::solution::Solution;
+
+/// A packet or a part of one, which parses from and displays as the puzzle's notation. Values
+/// are ordered the way the puzzle compares packets, in which an integer compares as a list
+/// holding just that integer, so values written differently may be equal, like `[1]` and `[[1]]`.
+#[derive(Clone, Debug)]
+pub enum Value {
+    /// A single number.
+    Integer(u32),
+    /// A list of values between brackets, separated by commas.
+    List(Vec<Box<Value>>),
+}
+
+peg::parser! {
+  grammar parser() for str {
+    pub rule package() -> Value
+      = "[" l:(value() ** ",") "]" { Value::List(l) }
+
+    rule value() -> Box<Value>
+      = integer() / list()
+
+    rule integer() -> Box<Value>
+      = n:$(['0'..='9']+) {? n.parse().or(Err("u32")).map(|nn| Box::new(Value::Integer(nn))) }
+
+    rule list() -> Box<Value>
+      = "[" l:(value() ** ",") "]" { Box::new(Value::List(l)) }
+  }
+}
+
+// Far deeper than any real packet. Parsing, comparing, printing and dropping a value all recurse
+// once per level, so a deeper one could overflow the stack.
+const MAX_DEPTH: usize = 100;
+
+impl std::str::FromStr for Value {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut depth = 0usize;
+        for (i, c) in s.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth = depth.saturating_sub(1),
+                _ => continue,
+            }
+            if depth > MAX_DEPTH {
+                let message = format!("lists nested deeper than {}", MAX_DEPTH);
+                return Err(ParseError::new(s, &s[i..i + 1], message));
+            }
+        }
+        parser::package(s).map_err(|e| {
+            let at = e.location.offset;
+            let len = s[at..].chars().next().map(char::len_utf8).unwrap_or(0);
+            ParseError::new(s, &s[at..at + len], format!("expected {}", e.expected))
+        })
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Integer(i) => write!(f, "{}", i),
+            Value::List(l) => {
+                if l.is_empty() {
+                    return write!(f, "[]");
+                }
+                let (last, init) = l.split_last().unwrap();
+                write!(
+                    f,
+                    "[{}{}]",
+                    init.iter()
+                        .map(|v| format!("{},", v))
+                        .collect::<Vec<String>>()
+                        .join(""),
+                    last
+                )
+            }
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::cmp::Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Value::Integer(l), Value::Integer(r)) => l.cmp(r),
+            (l @ Value::Integer(_), r @ Value::List(_)) => {
+                Value::List(vec![Box::new(l.clone())]).cmp(r)
+            }
+            (l @ Value::List(_), r @ Value::Integer(_)) => {
+                l.cmp(&Value::List(vec![Box::new(r.clone())]))
+            }
+            (Value::List(l), Value::List(r)) => l.cmp(r),
+        }
+    }
+}
+
+/// Sorts the packets of the distress signal.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Value, Value)>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Value, Value)>> {
+        Ok(split_blocks(input)
+            .map(|b| parse_pair(input, b))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(pairs: &Vec<(Value, Value)>, _: &()) -> Result<usize> {
+        Ok(pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (l, r))| l < r)
+            .map(|(i, _)| i + 1)
+            .sum())
+    }
+
+    fn part2(pairs: &Vec<(Value, Value)>, _: &()) -> Result<usize> {
+        let packets: Vec<&Value> = pairs.iter().flat_map(|(l, r)| [l, r]).collect();
+        let below = |divider: &Value| packets.iter().filter(|p| **p < divider).count();
+        // The first divider sorts before the second, which goes one place further down.
+        let (first, second) = ("[[2]]".parse()?, "[[6]]".parse()?);
+        Ok((below(&first) + 1) * (below(&second) + 2))
+    }
+}
+
+// A block of exactly two packets.
+fn parse_pair(input: &str, block: &str) -> Result<(Value, Value), ParseError> {
+    let mut lines = block.lines();
+    let mut packet = || {
+        let l = lines.next().ok_or_else(|| {
+            ParseError::new(input, &block[block.len()..], "expected another packet")
+        })?;
+        parse_token(input, l)
+    };
+    let pair = (packet()?, packet()?);
+    match lines.next() {
+        Some(l) => Err(ParseError::new(input, l, "expected a blank line")),
+        None => Ok(pair),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day13::parse(EXAMPLE)?;
+        assert_eq!(Day13::part1(&input, &())?, 13);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day13::parse(EXAMPLE)?;
+        assert_eq!(Day13::part2(&input, &())?, 140);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_and_display() -> Result<()> {
+        assert_eq!("[1,1,3,1,1]".parse::<Value>()?.to_string(), "[1,1,3,1,1]");
+        assert_eq!("[[1],4]".parse::<Value>()?.to_string(), "[[1],4]");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day13::parse("[1,2]\n[3,x]\n").unwrap_err().to_string(),
+            "input:2:4: expected one of \"[\", ['0'..='9']"
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_deep_nesting() {
+        let deep = |n| format!("{}{}", "[".repeat(n), "]".repeat(n));
+        assert!(deep(MAX_DEPTH).parse::<Value>().is_ok());
+        assert_eq!(
+            Day13::parse(&format!("[]\n{}\n", deep(200_000)))
+                .unwrap_err()
+                .to_string(),
+            "input:2:101: lists nested deeper than 100"
+        );
+    }
+
+    #[test]
+    fn test_ordering() -> Result<()> {
+        assert!("[1,1,3,1,1]".parse::<Value>()? < "[1,1,5,1,1]".parse::<Value>()?);
+        assert!("[[1],[2,3,4]]".parse::<Value>()? < "[[1],4]".parse::<Value>()?);
+        assert!("[9]".parse::<Value>()? > "[[8,7,6]]".parse::<Value>()?);
+        assert!("[[4,4],4,4]".parse::<Value>()? < "[[4,4],4,4,4]".parse::<Value>()?);
+        assert!("[7,7,7,7]".parse::<Value>()? > "[7,7,7]".parse::<Value>()?);
+        assert!("[]".parse::<Value>()? < "[3]".parse::<Value>()?);
+        assert!("[[[]]]".parse::<Value>()? > "[[]]".parse::<Value>()?);
+        assert!(
+            "[1,[2,[3,[4,[5,6,7]]]],8,9]".parse::<Value>()?
+                > "[1,[2,[3,[4,[5,6,0]]]],8,9]".parse::<Value>()?,
+        );
+        assert!("[1,2,3]".parse::<Value>()? == "[1,2,3]".parse::<Value>()?);
+        assert!("[1]".parse::<Value>()? == "[[1]]".parse::<Value>()?);
+        Ok(())
+    }
+
+    // Small integers and short lists, so that generated packets often compare equal deep down.
+    fn value() -> impl Strategy<Value = Value> {
+        (0..4u32)
+            .prop_map(Value::Integer)
+            .prop_recursive(4, 32, 4, |inner| {
+                prop::collection::vec(inner.prop_map(Box::new), 0..4).prop_map(Value::List)
+            })
+    }
+
+    fn packet() -> impl Strategy<Value = Value> {
+        prop::collection::vec(value().prop_map(Box::new), 0..4).prop_map(Value::List)
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips(p in packet()) {
+            let printed = p.to_string();
+            prop_assert_eq!(printed.parse::<Value>().unwrap().to_string(), printed);
+        }
+
+        #[test]
+        fn ordering_is_total(a in packet(), b in packet(), c in packet()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a == b, a.cmp(&b) == std::cmp::Ordering::Equal);
+            prop_assert_eq!(a.cmp(&a), std::cmp::Ordering::Equal);
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
+    }
+}
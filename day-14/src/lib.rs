@@ -0,0 +1,267 @@
+//! Day 14: Regolith Reservoir. [`Day14`] parses the rock paths into the set of positions of rock,
+//! and counts the units of sand that come to rest before sand falls into the abyss in part 1,
+//! and before the source is blocked by sand piling up on the floor in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::parse::{parse_token, split_once, ParseError};
+use aoc_common::point::Point2;
+use aoc_common::solution::{Part, Solution};
+use aoc_common::visual::{Frames, Glyph, Rgb, Visualize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashSet;
+use tracing::trace;
+
+/// The shape of the cave.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Where the sand pours in from, no further from the origin than the rocks can be.
+    #[serde(deserialize_with = "source")]
+    pub source: (i64, i64),
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { source: (500, 0) }
+    }
+}
+
+// The cave reaches from the rocks to the source, so it is bounded like the rocks are.
+fn source<'de, D: Deserializer<'de>>(d: D) -> Result<(i64, i64), D::Error> {
+    let (x, y) = <(i64, i64)>::deserialize(d)?;
+    let range = -MAX_COORDINATE..=MAX_COORDINATE;
+    if !range.contains(&x) || !range.contains(&y) {
+        return Err(D::Error::custom(format!(
+            "source ({}, {}) is further than {} from the origin",
+            x, y, MAX_COORDINATE
+        )));
+    }
+    Ok((x, y))
+}
+
+/// Simulates the sand falling into the cave.
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = HashSet<Point2>;
+    type Config = Config;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<HashSet<Point2>> {
+        let paths = input
+            .lines()
+            .map(|l| parse_segment(input, l))
+            .collect::<Result<Vec<Vec<Point2>>, _>>()?;
+        paths
+            .into_iter()
+            .map(interpolate_path)
+            .reduce(|a, b| Ok(&a? | &b?))
+            .ok_or(anyhow!("empty input"))?
+    }
+
+    fn part1(rocks: &HashSet<Point2>, config: &Config) -> Result<usize> {
+        pour(rocks, config, false, &mut |_| Ok(()))
+    }
+
+    fn part2(rocks: &HashSet<Point2>, config: &Config) -> Result<usize> {
+        pour(rocks, config, true, &mut |_| Ok(()))
+    }
+}
+
+/// Watch the cave fill up, one unit of sand at a time.
+impl Visualize for Day14 {
+    fn visualize(
+        rocks: &HashSet<Point2>,
+        config: &Config,
+        part: Part,
+        frames: Frames,
+    ) -> Result<()> {
+        let glyph = |c: &Cell| match c {
+            Cell::Air => Glyph::BLANK,
+            Cell::Rock => Glyph::new('#', Rgb::BROWN),
+            Cell::Sand => Glyph::new('o', Rgb::YELLOW),
+        };
+        let mut grains = 0;
+        pour(rocks, config, part == Part::Two, &mut |cave| {
+            grains += 1;
+            frames(
+                &cave.map(glyph),
+                &format!("unit {} of sand came to rest", grains),
+            )
+        })?;
+        Ok(())
+    }
+}
+
+// Pours sand until the source is blocked, or until sand falls into the abyss if there is no
+// floor, passing the cave to `rested` each time a unit comes to rest. Returns how many did.
+fn pour(
+    rocks: &HashSet<Point2>,
+    config: &Config,
+    floor: bool,
+    rested: &mut dyn FnMut(&Grid<Cell>) -> Result<()>,
+) -> Result<usize> {
+    let (mut cave, source) = cave(rocks, config.source.into())?;
+    let mut count = 0;
+    // Sand may pile up to the source before any of it falls into the abyss.
+    while cave[source] == Cell::Air {
+        let Some(p) = drop_sand(&cave, source, floor) else {
+            if floor {
+                bail!("sand fell through the floor");
+            }
+            break;
+        };
+        cave[p] = Cell::Sand;
+        count += 1;
+        trace!(grain = count, at = ?p, "sand came to rest");
+        rested(&cave)?;
+    }
+    Ok(count)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+// The cave down to just above the floor, wide enough for the sand to pile up to the source,
+// and the position of the source in it.
+fn cave(rocks: &HashSet<Point2>, source: Point2) -> Result<(Grid<Cell>, Pos)> {
+    let floor_y = rocks.iter().map(|p| p.y).chain([source.y]).max().unwrap() + 2;
+    let x_min = rocks
+        .iter()
+        .map(|p| p.x)
+        .chain([source.x - floor_y])
+        .min()
+        .unwrap()
+        - 1;
+    let x_max = rocks
+        .iter()
+        .map(|p| p.x)
+        .chain([source.x + floor_y])
+        .max()
+        .unwrap()
+        + 1;
+
+    let origin = Point2::new(x_min, 0);
+    let mut cave = Grid::new((x_max - x_min + 1) as usize, floor_y as usize, Cell::Air);
+    for rock in rocks {
+        cave[Pos::try_from(*rock - origin)?] = Cell::Rock;
+    }
+    let source = Pos::try_from(source - origin)?;
+    if cave[source] != Cell::Air {
+        bail!("the source is blocked by a rock");
+    }
+    Ok((cave, source))
+}
+
+// Where a unit of sand dropped from `source` comes to rest, if it does. Without a floor, sand
+// reaching the bottom row falls into the abyss; with one, it rests there.
+fn drop_sand(cave: &Grid<Cell>, source: Pos, floor: bool) -> Option<Pos> {
+    let (mut x, mut y) = source;
+    loop {
+        if y + 1 == cave.height() {
+            return floor.then_some((x, y));
+        }
+        match [x, x - 1, x + 1]
+            .into_iter()
+            .find(|nx| cave[(*nx, y + 1)] == Cell::Air)
+        {
+            Some(nx) => (x, y) = (nx, y + 1),
+            None => return Some((x, y)),
+        }
+    }
+}
+
+fn interpolate_path<I: IntoIterator<Item = Point2>>(path: I) -> Result<HashSet<Point2>> {
+    let mut points = HashSet::new();
+    let mut start: Option<Point2> = None;
+    for end in path {
+        let mut p = start.unwrap_or(end);
+        let step = (end - p).signum();
+        if step * p.chebyshev(end) != end - p {
+            bail!("{} -> {} is not a straight line", p, end);
+        }
+        points.insert(p);
+        while p != end {
+            p += step;
+            points.insert(p);
+        }
+        start = Some(end);
+    }
+    Ok(points)
+}
+
+/// The corners of the rock path on the line `s` of `input`, which must be within
+/// 1000 of the origin.
+pub fn parse_segment(input: &str, s: &str) -> Result<Vec<Point2>, ParseError> {
+    s.split(" -> ").map(|p| parse_point(input, p)).collect()
+}
+
+// Far beyond any real scan, but small enough that the cave, which reaches as far down as the
+// lowest rock and twice as far across, fits in memory.
+const MAX_COORDINATE: i64 = 1_000;
+
+fn parse_point(input: &str, s: &str) -> Result<Point2, ParseError> {
+    let (x, y) = split_once(input, s, ",")?;
+    let p = Point2::new(parse_token(input, x)?, parse_token(input, y)?);
+    let range = -MAX_COORDINATE..=MAX_COORDINATE;
+    if !range.contains(&p.x) || !range.contains(&p.y) {
+        return Err(ParseError::new(
+            input,
+            s,
+            format!("{} is further than {} from the origin", p, MAX_COORDINATE),
+        ));
+    }
+    Ok(p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day14::parse(EXAMPLE)?;
+        assert_eq!(Day14::part1(&input, &Config::default())?, 24);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day14::parse(EXAMPLE)?;
+        assert_eq!(Day14::part2(&input, &Config::default())?, 93);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config() {
+        let config = |s: &str| aoc_common::config::parse::<Config>(&s.parse().unwrap());
+        assert_eq!(
+            config("source = [-1000, 1000]").unwrap().source,
+            (-1000, 1000)
+        );
+        assert!(config("source = [500, 1001]").is_err());
+        assert!(config("source = [-9223372036854775808, 0]").is_err());
+    }
+}
@@ -0,0 +1,196 @@
+//! Day 15: Beacon Exclusion Zone. [`Day15`] parses the sensors' reports, and counts the
+//! positions in a row where the distress beacon cannot be in part 1, and finds it in the search
+//! area to compute its tuning frequency in part 2.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, Result};
+use aoc_common::parse::{parse_token, split_once, strip_prefix, ParseError};
+use aoc_common::point::Point2;
+use aoc_common::solution::Solution;
+use serde::Deserialize;
+use std::collections::HashSet;
+use tracing::debug;
+
+struct Sensor {
+    position: Point2,
+    ray: i64,
+}
+
+impl Sensor {
+    fn new(position: Point2, beacon: Point2) -> Sensor {
+        Sensor {
+            position,
+            ray: position.manhattan(beacon),
+        }
+    }
+
+    fn covers(&self, p: Point2) -> bool {
+        self.position.manhattan(p) <= self.ray
+    }
+
+    fn x_min(&self) -> i64 {
+        self.position.x - self.ray
+    }
+
+    fn x_max(&self) -> i64 {
+        self.position.x + self.ray
+    }
+
+    // Positions just out of reach, walking each side of the diamond from a corner.
+    fn perimeter(&self) -> HashSet<Point2> {
+        let mut p = HashSet::new();
+        for d in 0..=(self.ray + 1) {
+            let r = self.ray - d + 1;
+            for (dx, dy) in [(d, r), (d, -r), (-d, r), (-d, -r)] {
+                p.insert(self.position + Point2::new(dx, dy));
+            }
+        }
+        p
+    }
+}
+
+/// The position of a sensor and of the beacon closest to it.
+pub type Report = (Point2, Point2);
+
+fn parse_report(input: &str, line: &str) -> Result<Report, ParseError> {
+    let rest = strip_prefix(input, line, "Sensor at ")?;
+    let (sensor, beacon) = split_once(input, rest, ": closest beacon is at ")?;
+    Ok((
+        parse_position(input, sensor)?,
+        parse_position(input, beacon)?,
+    ))
+}
+
+// A few times further than any real report, but small enough that the distances cannot overflow
+// and the rows stay quick to scan.
+const MAX_COORDINATE: i64 = 10_000_000;
+
+fn parse_position(input: &str, s: &str) -> Result<Point2, ParseError> {
+    let rest = strip_prefix(input, s, "x=")?;
+    let (x, y) = split_once(input, rest, ", y=")?;
+    let p = Point2::new(parse_token(input, x)?, parse_token(input, y)?);
+    let range = -MAX_COORDINATE..=MAX_COORDINATE;
+    if !range.contains(&p.x) || !range.contains(&p.y) {
+        return Err(ParseError::new(
+            input,
+            s,
+            format!("{} is further than {} from the origin", p, MAX_COORDINATE),
+        ));
+    }
+    Ok(p)
+}
+
+/// Where to look for the distress beacon.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Row scanned in part 1.
+    pub row: i64,
+    /// The distress beacon is within `0..=bound` on both axes.
+    pub bound: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            row: 2_000_000,
+            bound: 4_000_000,
+        }
+    }
+}
+
+/// Locates the distress beacon from the sensors' reports.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Report>;
+    type Config = Config;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Report>> {
+        Ok(input
+            .lines()
+            .map(|l| parse_report(input, l))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(data: &Vec<Report>, config: &Config) -> Result<usize> {
+        count_covered(data, config.row)
+    }
+
+    fn part2(data: &Vec<Report>, config: &Config) -> Result<i64> {
+        tuning_frequency(data, config.bound)
+    }
+}
+
+// Positions in row `y` where the distress beacon cannot be.
+fn count_covered(data: &[Report], y: i64) -> Result<usize> {
+    let sensors: Vec<Sensor> = data.iter().map(|(s, b)| Sensor::new(*s, *b)).collect();
+
+    let beacons: HashSet<Point2> = data.iter().map(|(_, b)| *b).collect();
+    // The sensor furthest left is not always the one reaching furthest left.
+    let left = sensors
+        .iter()
+        .map(|s| s.x_min())
+        .min()
+        .ok_or(anyhow!("no sensors"))?;
+    let right = sensors.iter().map(|s| s.x_max()).max().unwrap();
+
+    Ok((left..=right)
+        .map(|x| Point2::new(x, y))
+        .filter(|p| !beacons.contains(p))
+        .filter(|p| sensors.iter().any(|s| s.covers(*p)))
+        .count())
+}
+
+// Tuning frequency of the only position in `0..=l` on both axes not covered by any sensor.
+fn tuning_frequency(data: &[Report], l: i64) -> Result<i64> {
+    let sensors: Vec<Sensor> = data.iter().map(|(s, b)| Sensor::new(*s, *b)).collect();
+
+    let perimeters = sensors
+        .iter()
+        .enumerate()
+        .fold(HashSet::<Point2>::new(), |t, (i, s)| {
+            debug!(sensor = i, of = sensors.len(), "adding the perimeter");
+            &t | &s.perimeter()
+        });
+
+    let beacon = perimeters
+        .iter()
+        .filter(|p| (0..=l).contains(&p.x) && (0..=l).contains(&p.y))
+        .find(|p| !sensors.iter().any(|s| s.covers(**p)))
+        .ok_or(anyhow!("no distress beacon found"))?;
+
+    Ok(beacon.x * 4_000_000 + beacon.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    const EXAMPLE_CONFIG: Config = Config { row: 10, bound: 20 };
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day15::parse(EXAMPLE)?;
+        assert_eq!(Day15::part1(&input, &EXAMPLE_CONFIG)?, 26);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = Day15::parse(EXAMPLE)?;
+        assert_eq!(Day15::part2(&input, &EXAMPLE_CONFIG)?, 56000011);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rejects_far_positions() {
+        let report = "Sensor at x=9223372036854775807, y=0: closest beacon is at x=-9223372036854775808, y=0\n";
+        assert!(Day15::parse(report).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+//! Day 17: Pyroclastic Flow. [`Day17`] parses the jet pattern into the direction of each push,
+//! and measures the height of the tower after the rocks have fallen in part 1. Part 2 is not
+//! solved yet.
+#![warn(missing_docs)]
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::direction::Direction4;
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::point::Point2;
+use aoc_common::solution::{Part, Solution};
+use aoc_common::visual::{Frame, Frames, Glyph, Rgb, Visualize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashSet;
+use tracing::{debug, trace};
+
+fn parse_jet(input: &str, jet: &str) -> Result<Direction4, ParseError> {
+    match jet {
+        "<" => Ok(Direction4::Left),
+        ">" => Ok(Direction4::Right),
+        _ => Err(ParseError::new(input, jet, "expected '<' or '>'")),
+    }
+}
+
+// The chamber has `y` growing upwards, so falling is towards negative `y`.
+const FALL: Point2 = Point2::new(0, -1);
+
+#[derive(Debug, Clone)]
+struct Piece {
+    coords: Vec<Point2>,
+}
+
+impl Piece {
+    fn new<const N: usize>(coords: [(i64, i64); N]) -> Piece {
+        Piece {
+            coords: coords.into_iter().map(Point2::from).collect(),
+        }
+    }
+
+    fn mv(&self, d: Point2) -> Piece {
+        Piece {
+            coords: self.coords.iter().map(|p| *p + d).collect(),
+        }
+    }
+
+    fn x_min(&self) -> i64 {
+        self.coords.iter().map(|p| p.x).min().unwrap()
+    }
+
+    fn x_max(&self) -> i64 {
+        self.coords.iter().map(|p| p.x).max().unwrap()
+    }
+
+    fn y_min(&self) -> i64 {
+        self.coords.iter().map(|p| p.y).min().unwrap()
+    }
+
+    fn y_max(&self) -> i64 {
+        self.coords.iter().map(|p| p.y).max().unwrap()
+    }
+
+    fn coord_set(&self) -> HashSet<Point2> {
+        self.coords.iter().cloned().collect()
+    }
+}
+
+/// The chamber and how long to watch it.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Units of width, counted from the left wall. At least 4, the width of the widest rock, and
+    /// at most 1000.
+    #[serde(deserialize_with = "chamber_width")]
+    pub chamber_width: i64,
+    /// Rocks that fall in part 1. From 1 to 1,000,000.
+    #[serde(deserialize_with = "rocks")]
+    pub rocks: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            chamber_width: 7,
+            rocks: 2022,
+        }
+    }
+}
+
+// Frames of the chamber are drawn wall to wall.
+const MAX_CHAMBER_WIDTH: i64 = 1_000;
+
+// Narrower than the widest rock, the chamber could not take the rocks at all.
+fn chamber_width<'de, D: Deserializer<'de>>(d: D) -> Result<i64, D::Error> {
+    let width = i64::deserialize(d)?;
+    if width < 4 {
+        return Err(D::Error::custom(format!(
+            "chamber_width is {}, narrower than the widest rock",
+            width
+        )));
+    }
+    if width > MAX_CHAMBER_WIDTH {
+        return Err(D::Error::custom(format!(
+            "chamber_width is {}, wider than {}",
+            width, MAX_CHAMBER_WIDTH
+        )));
+    }
+    Ok(width)
+}
+
+// Every rock that falls stays in the tower, so its size bounds the memory the tower takes.
+const MAX_ROCKS: usize = 1_000_000;
+
+// There is no tower to measure before a rock has fallen.
+fn rocks<'de, D: Deserializer<'de>>(d: D) -> Result<usize, D::Error> {
+    let rocks = usize::deserialize(d)?;
+    if !(1..=MAX_ROCKS).contains(&rocks) {
+        return Err(D::Error::custom(format!(
+            "{} rocks, but from 1 to {} can fall",
+            rocks, MAX_ROCKS
+        )));
+    }
+    Ok(rocks)
+}
+
+/// Stacks the falling rocks into a tower.
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Direction4>;
+    type Config = Config;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse(input: &str) -> Result<Vec<Direction4>> {
+        let line = input.lines().next().ok_or(anyhow!("empty input"))?;
+        if line.is_empty() {
+            bail!("no jets of gas");
+        }
+        Ok(line
+            .char_indices()
+            .map(|(i, c)| parse_jet(input, &line[i..i + c.len_utf8()]))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(movements: &Vec<Direction4>, config: &Config) -> Result<i64> {
+        let tower = simulate(
+            pieces().iter(),
+            movements.iter(),
+            config.chamber_width,
+            config.rocks,
+            &mut |_, _, _| Ok(()),
+        )?;
+        let y_max = tower.iter().map(|p| p.y).max().unwrap();
+        Ok(y_max + 1)
+    }
+
+    fn part2(_: &Vec<Direction4>, _: &Config) -> Result<i64> {
+        bail!("part 2 is not solved yet")
+    }
+}
+
+// Rows of the chamber in view when watching the tower grow.
+const WINDOW: i64 = 40;
+
+/// Watch the top of the tower as each rock comes to rest.
+impl Visualize for Day17 {
+    fn visualize(
+        movements: &Vec<Direction4>,
+        config: &Config,
+        part: Part,
+        frames: Frames,
+    ) -> Result<()> {
+        if part == Part::Two {
+            bail!("part 2 is not solved yet");
+        }
+        let mut rocks = 0;
+        simulate(
+            pieces().iter(),
+            movements.iter(),
+            config.chamber_width,
+            config.rocks,
+            &mut |tower, piece, y_max| {
+                rocks += 1;
+                let top = y_max.max(WINDOW - 2);
+                let caption = format!(
+                    "rock {} came to rest: the tower is {} high",
+                    rocks,
+                    y_max + 1
+                );
+                frames(
+                    &frame(tower, piece, config.chamber_width, top, WINDOW),
+                    &caption,
+                )
+            },
+        )?;
+        Ok(())
+    }
+}
+
+fn pieces() -> [Piece; 5] {
+    let minus = Piece::new([(0, 0), (1, 0), (2, 0), (3, 0)]);
+    let plus = Piece::new([(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]);
+    let rev_l = Piece::new([(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+    let pipe = Piece::new([(0, 0), (0, 1), (0, 2), (0, 3)]);
+    let square = Piece::new([(0, 0), (0, 1), (1, 0), (1, 1)]);
+
+    [minus, plus, rev_l, pipe, square]
+}
+
+// Called with the tower, the rock that came to rest on it and the new height, before the rock
+// joins the tower.
+type Rested<'a> = &'a mut dyn FnMut(&HashSet<Point2>, &Piece, i64) -> Result<()>;
+
+fn simulate<
+    'a,
+    P: Iterator<Item = &'a Piece> + Clone,
+    M: Iterator<Item = &'a Direction4> + Clone,
+>(
+    pieces: P,
+    movements: M,
+    x_max: i64,
+    n: usize,
+    rested: Rested,
+) -> Result<HashSet<Point2>> {
+    let mut tower: HashSet<Point2> = HashSet::new();
+    let mut y_max = -1;
+    let mut movements = movements.cycle();
+    for (rock, piece) in pieces.cycle().take(n).enumerate() {
+        let mut p = piece.mv(Point2::new(2, y_max + 4));
+        loop {
+            let jet = movements.next().unwrap();
+
+            let next_p = p.mv(jet.unit());
+            if next_p.x_min() >= 0
+                && next_p.x_max() < x_max
+                && next_p.coord_set().is_disjoint(&tower)
+            {
+                p = next_p;
+            }
+
+            let next_p = p.mv(FALL);
+            if next_p.y_min() < 0 || !next_p.coord_set().is_disjoint(&tower) {
+                y_max = std::cmp::max(y_max, p.y_max());
+                trace!("\n{}", frame(&tower, &p, x_max, y_max, y_max + 2));
+                rested(&tower, &p, y_max)?;
+                tower.extend(&p.coords);
+                debug!(rock, height = y_max + 1, "rock came to rest");
+                break;
+            } else {
+                p = next_p;
+            }
+        }
+    }
+    Ok(tower)
+}
+
+// `rows` rows of the chamber `width` wide from `top` down, between its walls and above its floor,
+// with `piece` drawn as `@`.
+fn frame(tower: &HashSet<Point2>, piece: &Piece, width: i64, top: i64, rows: i64) -> Frame {
+    let piece = piece.coord_set();
+    let mut frame = Grid::new(width as usize + 2, rows as usize, Glyph::BLANK);
+    for (row, y) in (top - rows + 1..=top).rev().enumerate() {
+        for x in -1..=width {
+            let p = Point2::new(x, y);
+            frame[((x + 1) as usize, row)] = if y == -1 {
+                Glyph::new(if x == -1 || x == width { '+' } else { '-' }, Rgb::GREY)
+            } else if y < -1 {
+                Glyph::BLANK
+            } else if x == -1 || x == width {
+                Glyph::new('|', Rgb::GREY)
+            } else if tower.contains(&p) {
+                Glyph::new('#', Rgb::BROWN)
+            } else if piece.contains(&p) {
+                Glyph::new('@', Rgb::RED)
+            } else {
+                Glyph::new('.', Rgb::GREY)
+            };
+        }
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = Day17::parse(EXAMPLE)?;
+        assert_eq!(Day17::part1(&input, &Config::default())?, 3068);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config() {
+        let config = |s: &str| aoc_common::config::parse::<Config>(&s.parse().unwrap());
+        assert_eq!(config("chamber_width = 4").unwrap().chamber_width, 4);
+        assert!(config("chamber_width = 3").is_err());
+        assert!(config("chamber_width = 1001").is_err());
+        assert_eq!(config("rocks = 1").unwrap().rocks, 1);
+        assert!(config("rocks = 0").is_err());
+        assert!(config("rocks = 1000001").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_no_jets() {
+        assert!(Day17::parse("\n>>\n").is_err());
+    }
+}
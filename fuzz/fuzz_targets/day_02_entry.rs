@@ -0,0 +1,10 @@
+#![no_main]
+
+use day_02::{Column, Entry, Shape};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = input.parse::<Entry>();
+    let _ = input.parse::<Shape>();
+    let _ = input.parse::<Column>();
+});
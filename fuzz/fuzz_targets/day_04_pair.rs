@@ -0,0 +1,16 @@
+#![no_main]
+
+use day_04::{Pair, Range};
+use libfuzzer_sys::fuzz_target;
+
+// A pair written out again in the puzzle's notation parses back to the same sections.
+fuzz_target!(|input: &str| {
+    let _ = input.parse::<Range>();
+    if let Ok(pair) = input.parse::<Pair>() {
+        let (l, r) = (&pair.left, &pair.right);
+        let printed = format!("{}-{},{}-{}", l.from, l.to, r.from, r.to);
+        let again: Pair = printed.parse().unwrap();
+        assert_eq!((again.left.from, again.left.to), (l.from, l.to));
+        assert_eq!((again.right.from, again.right.to), (r.from, r.to));
+    }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use day_05::Move;
+use libfuzzer_sys::fuzz_target;
+
+// A move written out again in the puzzle's notation parses back to the same move.
+fuzz_target!(|input: &str| {
+    if let Ok(m) = input.parse::<Move>() {
+        let printed = format!("move {} from {} to {}", m.n(), m.from(), m.to());
+        let again: Move = printed.parse().unwrap();
+        assert_eq!(
+            (again.n(), again.from(), again.to()),
+            (m.n(), m.from(), m.to())
+        );
+    }
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_07::parse_sizes;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = parse_sizes(input);
+});
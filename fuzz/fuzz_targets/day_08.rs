@@ -0,0 +1,14 @@
+#![no_main]
+
+use aoc_common::solution::Solution;
+use day_08::Day08;
+use libfuzzer_sys::fuzz_target;
+
+// A grid prints back as its input, minus any trailing newline, so printing it and parsing it
+// again must give the same grid.
+fuzz_target!(|input: &str| {
+    if let Ok(grid) = Day08::parse(input) {
+        let printed = grid.to_string();
+        assert_eq!(Day08::parse(&printed).unwrap(), grid, "{:?}", printed);
+    }
+});
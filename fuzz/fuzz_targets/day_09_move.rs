@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_09::Move;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = input.parse::<Move>();
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use day_10::Instruction;
+use libfuzzer_sys::fuzz_target;
+
+// An instruction written out again in the puzzle's notation parses back to the same instruction.
+fuzz_target!(|input: &str| {
+    if let Ok(instruction) = input.parse::<Instruction>() {
+        let printed = match instruction {
+            Instruction::AddX(n) => format!("addx {}", n),
+            Instruction::Noop => "noop".to_string(),
+        };
+        let again: Instruction = printed.parse().unwrap();
+        assert_eq!(format!("{:?}", again), format!("{:?}", instruction));
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use aoc_common::solution::Solution;
+use day_13::{Day13, Value};
+use libfuzzer_sys::fuzz_target;
+
+// A packet prints as its canonical form, which parses back to a packet printed the same. Packets
+// written differently can be equal, so comparing the printed forms is the stricter check.
+fuzz_target!(|input: &str| {
+    let _ = Day13::parse(input);
+    if let Ok(value) = input.parse::<Value>() {
+        let printed = value.to_string();
+        assert_eq!(printed.parse::<Value>().unwrap().to_string(), printed);
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use day_13::Value;
+use libfuzzer_sys::fuzz_target;
+use std::cmp::Ordering;
+
+// The packet order must be antisymmetric and transitive, and agree with equality.
+fuzz_target!(|input: &str| {
+    let values: Vec<Value> = input.lines().filter_map(|l| l.parse().ok()).collect();
+    if let [a, b, c, ..] = &values[..] {
+        assert_eq!(a.cmp(b), b.cmp(a).reverse());
+        assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+        if a <= b && b <= c {
+            assert!(a <= c);
+        }
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use day_14::parse_segment;
+use libfuzzer_sys::fuzz_target;
+
+// A rock path written out again in the puzzle's notation parses back to the same corners.
+fuzz_target!(|input: &str| {
+    if let Ok(corners) = parse_segment(input, input) {
+        let printed: Vec<String> = corners.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+        let printed = printed.join(" -> ");
+        assert_eq!(parse_segment(&printed, &printed).unwrap(), corners);
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use aoc::answers::Answers;
+use aoc::days::DAYS;
+use aoc_common::solution::Part;
+use libfuzzer_sys::fuzz_target;
+use std::sync::OnceLock;
+
+// Whatever a day parses, it must solve both parts of or fail with an error, never panic. The first
+// byte picks the day. The parameters are the example's, which keep days like 15 as quick as the
+// inputs are small.
+fuzz_target!(|data: (u8, &str)| {
+    static EXAMPLES: OnceLock<Answers> = OnceLock::new();
+    let examples = EXAMPLES.get_or_init(|| Answers::load(Answers::examples_path()).unwrap());
+    let (day, input) = data;
+    let day = &DAYS[day as usize % DAYS.len()];
+    let config = examples.config(day.number).cloned().unwrap_or_default();
+    let _ = (day.solve)(input, &config, &Part::ALL);
+});
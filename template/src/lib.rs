@@ -0,0 +1,50 @@
+//! The puzzle, what [`DayNn`] parses its input into, and what each part computes from that.
+#![warn(missing_docs)]
+
+use anyhow::Result;
+use aoc_common::input::lines;
+use aoc_common::solution::Solution;
+
+/// What the solution finds.
+pub struct DayNn;
+
+impl Solution for DayNn {
+    type Input = Vec<String>;
+    type Config = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(lines(input))
+    }
+
+    fn part1(_input: &Vec<String>, _: &()) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn part2(_input: &Vec<String>, _: &()) -> Result<usize> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = DayNn::parse(EXAMPLE)?;
+        assert_eq!(DayNn::part1(&input, &())?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = DayNn::parse(EXAMPLE)?;
+        assert_eq!(DayNn::part2(&input, &())?, 0);
+        Ok(())
+    }
+}
@@ -1,25 +1,7 @@
 use anyhow::Result;
-use std::fs::File;
-use std::io::prelude::*;
-use std::io::BufReader;
+use aoc_common::output;
+use day_nn::DayNn;
 
 fn main() -> Result<()> {
-    let input = read_input("input.txt")?;
-    println!("{:?}", input);
-    Ok(())
-}
-
-fn read_input<P: AsRef<std::path::Path>>(path: P) -> Result<Vec<String>> {
-    let file = File::open(path)?;
-    BufReader::new(file).lines().map(|l| Ok(l?)).collect()
-}
-
-#[cfg(test)]
-mod tests {
-    use anyhow::Result;
-
-    #[test]
-    fn test_foo() -> Result<()> {
-        Ok(())
-    }
+    output::run::<DayNn>(0)
 }